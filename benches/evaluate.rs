@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_deck::deck::{Card, CardSet, Deck};
+use rust_deck::{evaluator, hand};
+
+// The same thousand seven card hands for both evaluators
+fn hands() -> Vec<Vec<Card>> {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut deck = Deck::new();
+    (0..1000)
+        .map(|_| {
+            deck.shuffle_with(&mut rng);
+            (0..7).map(|_| deck.deal()).collect()
+        })
+        .collect()
+}
+
+fn bench_evaluate(c: &mut Criterion) {
+    let hands = hands();
+    let sets: Vec<CardSet> = hands.iter().map(|cards| cards.iter().copied().collect()).collect();
+
+    let mut group = c.benchmark_group("evaluate 1000 seven card hands");
+    group.bench_function("reference", |b| {
+        b.iter(|| hands.iter().map(|cards| hand::evaluate(black_box(cards))).max())
+    });
+    group.bench_function("lookup", |b| {
+        b.iter(|| sets.iter().map(|&cards| evaluator::evaluate(black_box(cards))).max())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_evaluate);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use crate::chips::Chips;
+use crate::event::Street;
+use serde::{Deserialize, Serialize};
+
+// How much a player may raise by on each street
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BettingStructure {
+    // Any raise from the size of the last raise up to the whole bank
+    #[default]
+    NoLimit,
+    // Any raise from the size of the last raise up to the size of the pot
+    PotLimit,
+    // Every bet and raise is the small bet before the turn and the big bet from
+    // the turn on. `raise_cap` is the most bets and raises allowed on a street,
+    // counting the big blind before the flop.
+    FixedLimit { small_bet: Chips, big_bet: Chips, raise_cap: u32 },
+}
+
+// Dead money posted before the cards are dealt
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Ante {
+    #[default]
+    None,
+    // Every player dealt in posts the ante
+    PerPlayer(Chips),
+    // The big blind posts a single ante for the whole table
+    BigBlind(Chips),
+}
+
+// The smallest and largest amount a raise may add to the current bid, before
+// the player's bank is taken into account
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RaiseLimits {
+    pub min: Chips,
+    pub max: Chips,
+}
+
+impl BettingStructure {
+    // `last_raise` is the size of the last full raise on this street, `pot` holds
+    // every chip bet so far including this street, and `bets` counts the bets and
+    // raises made on this street. None once no more raises are allowed.
+    pub fn raise_limits(&self, street: Street, last_raise: Chips, pot: Chips, to_call: Chips, bets: u32) -> Option<RaiseLimits> {
+        match *self {
+            BettingStructure::NoLimit => Some(RaiseLimits { min: last_raise, max: Chips::MAX }),
+            BettingStructure::PotLimit => {
+                // Raising the pot is calling first and then betting everything in the middle
+                let max = (pot + to_call).max(last_raise);
+                Some(RaiseLimits { min: last_raise, max })
+            },
+            BettingStructure::FixedLimit { small_bet, big_bet, raise_cap } => {
+                if bets >= raise_cap {
+                    return None;
+                }
+                let bet = match street {
+                    Street::PreFlop | Street::Flop => small_bet,
+                    Street::Turn | Street::River | Street::Showdown => big_bet,
+                };
+                Some(RaiseLimits { min: bet, max: bet })
+            },
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::chips::Chips;
+use crate::game::{Game, GameResult, SeatError};
+use crate::player::Player;
+use std::error::Error;
+use std::fmt;
+
+// The least and most a player may have in front of them after buying in
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BuyIn {
+    pub min: Chips,
+    pub max: Chips,
+}
+
+// Why a cash table refused a player's request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CashError {
+    Seat(SeatError),
+    BuyInOutOfRange { amount: Chips, buy_in: BuyIn },
+    // Rebuys are for busted players, everyone else tops up
+    StillHasChips(usize),
+    Busted(usize),
+}
+
+impl fmt::Display for CashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CashError::Seat(err) => write!(f, "{}", err),
+            CashError::BuyInOutOfRange { amount, buy_in } => {
+                write!(f, "{} is not between the minimum buy in of {} and the maximum of {}", amount, buy_in.min, buy_in.max)
+            },
+            CashError::StillHasChips(seat) => write!(f, "seat {} still has chips, top up instead", seat),
+            CashError::Busted(seat) => write!(f, "seat {} has no chips, rebuy instead", seat),
+        }
+    }
+}
+
+impl Error for CashError {}
+
+impl From<SeatError> for CashError {
+    fn from(err: SeatError) -> Self {
+        CashError::Seat(err)
+    }
+}
+
+// A `Game` played for cash. Players come and go between hands, buy chips within
+// the table's limits, and can sit out for a while without giving up their seat.
+pub struct CashTable {
+    game: Game,
+    buy_in: BuyIn,
+    max_seats: usize,
+}
+
+impl CashTable {
+    // Players already at `game` keep the banks they have
+    pub fn new(game: Game, buy_in: BuyIn, max_seats: usize) -> CashTable {
+        CashTable { game, buy_in, max_seats }
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    // Gives access to the table, e.g. to subscribe to its events
+    pub fn game_mut(&mut self) -> &mut Game {
+        &mut self.game
+    }
+
+    pub fn buy_in(&self) -> BuyIn {
+        self.buy_in
+    }
+
+    // Deals a hand when at least two players are sitting in, returns
+    // `GameResult::Waiting` otherwise
+    pub fn play_hand(&mut self) -> GameResult {
+        if self.game.players().iter().filter(|player| player.can_play()).count() < 2 {
+            return GameResult::Waiting;
+        }
+        self.game.play_hand()
+    }
+
+    // Seats `player` with `amount` in chips. Returns their seat.
+    pub fn join(&mut self, mut player: Player, amount: Chips) -> Result<usize, CashError> {
+        self.check_buy_in(amount)?;
+        if self.game.players().len() >= self.max_seats {
+            return Err(SeatError::TableFull.into());
+        }
+        player.bank = amount;
+        player.sitting_out = false;
+        Ok(self.game.seat_player(player)?)
+    }
+
+    // Cashes the player in `seat` out, handing them back with their bank
+    pub fn leave(&mut self, seat: usize) -> Result<Player, CashError> {
+        Ok(self.game.remove_player(seat)?)
+    }
+
+    // Buys a busted player back in for `amount`
+    pub fn rebuy(&mut self, seat: usize, amount: Chips) -> Result<(), CashError> {
+        let player = self.between_hands(seat)?;
+        if !player.bank.is_zero() {
+            return Err(CashError::StillHasChips(seat));
+        }
+        self.check_buy_in(amount)?;
+        self.game.player_mut(seat)?.bank = amount;
+        Ok(())
+    }
+
+    // Adds `amount` to a player's chips, as long as it keeps them within the
+    // maximum buy in
+    pub fn top_up(&mut self, seat: usize, amount: Chips) -> Result<(), CashError> {
+        let player = self.between_hands(seat)?;
+        if player.bank.is_zero() {
+            return Err(CashError::Busted(seat));
+        }
+        let bank = player.bank.checked_add(amount).unwrap_or(Chips::MAX);
+        if amount.is_zero() || bank > self.buy_in.max {
+            return Err(CashError::BuyInOutOfRange { amount, buy_in: self.buy_in });
+        }
+        self.game.player_mut(seat)?.bank = bank;
+        Ok(())
+    }
+
+    // The player keeps their seat and chips but is not dealt in until they sit
+    // back in
+    pub fn sit_out(&mut self, seat: usize) -> Result<(), CashError> {
+        self.between_hands(seat)?;
+        self.game.player_mut(seat)?.sitting_out = true;
+        Ok(())
+    }
+
+    pub fn sit_in(&mut self, seat: usize) -> Result<(), CashError> {
+        self.between_hands(seat)?;
+        self.game.player_mut(seat)?.sitting_out = false;
+        Ok(())
+    }
+
+    fn between_hands(&self, seat: usize) -> Result<&Player, CashError> {
+        if self.game.to_act().is_some() {
+            return Err(SeatError::HandInProgress.into());
+        }
+        self.game.players().get(seat).ok_or(CashError::Seat(SeatError::NoSuchSeat(seat)))
+    }
+
+    fn check_buy_in(&self, amount: Chips) -> Result<(), CashError> {
+        if amount < self.buy_in.min || amount > self.buy_in.max {
+            return Err(CashError::BuyInOutOfRange { amount, buy_in: self.buy_in });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+
+// An amount of money counted in the smallest unit the table plays with. When a
+// table plays for cash one chip is one cent.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Chips(u64);
+
+impl Chips {
+    pub const ZERO: Chips = Chips(0);
+    pub const MAX: Chips = Chips(u64::MAX);
+    pub const CENTS_PER_DOLLAR: u64 = 100;
+
+    pub const fn new(count: u64) -> Chips {
+        Chips(count)
+    }
+
+    pub const fn from_dollars(dollars: u64) -> Chips {
+        Chips(dollars * Chips::CENTS_PER_DOLLAR)
+    }
+
+    pub const fn count(self) -> u64 {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: Chips) -> Option<Chips> {
+        self.0.checked_add(other.0).map(Chips)
+    }
+
+    pub fn checked_sub(self, other: Chips) -> Option<Chips> {
+        self.0.checked_sub(other.0).map(Chips)
+    }
+
+    pub fn checked_mul(self, factor: u64) -> Option<Chips> {
+        self.0.checked_mul(factor).map(Chips)
+    }
+
+    pub fn saturating_sub(self, other: Chips) -> Chips {
+        Chips(self.0.saturating_sub(other.0))
+    }
+
+    // Divides the chips evenly `ways` ways, returning each share and the odd
+    // chips left over.
+    pub fn split(self, ways: u64) -> (Chips, Chips) {
+        if ways == 0 {
+            return (Chips::ZERO, self);
+        }
+        (Chips(self.0 / ways), Chips(self.0 % ways))
+    }
+
+    // Formats the amount as dollars and cents, e.g. `$1.25`
+    pub fn dollars(self) -> Dollars {
+        Dollars(self)
+    }
+}
+
+impl fmt::Display for Chips {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Chips {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse::<u64>().map(Chips)
+    }
+}
+
+pub struct Dollars(Chips);
+
+impl fmt::Display for Dollars {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cents = self.0.count();
+        write!(f, "${}.{:02}", cents / Chips::CENTS_PER_DOLLAR, cents % Chips::CENTS_PER_DOLLAR)
+    }
+}
+
+impl Add for Chips {
+    type Output = Chips;
+
+    fn add(self, other: Chips) -> Chips {
+        self.checked_add(other).expect("chip count overflowed")
+    }
+}
+
+impl AddAssign for Chips {
+    fn add_assign(&mut self, other: Chips) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Chips {
+    type Output = Chips;
+
+    fn sub(self, other: Chips) -> Chips {
+        self.checked_sub(other).expect("chip count went negative")
+    }
+}
+
+impl SubAssign for Chips {
+    fn sub_assign(&mut self, other: Chips) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<u64> for Chips {
+    type Output = Chips;
+
+    fn mul(self, factor: u64) -> Chips {
+        self.checked_mul(factor).expect("chip count overflowed")
+    }
+}
+
+impl Sum for Chips {
+    fn sum<I: Iterator<Item = Chips>>(iter: I) -> Chips {
+        iter.fold(Chips::ZERO, |total, chips| total + chips)
+    }
+}
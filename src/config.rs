@@ -0,0 +1,183 @@
+use crate::betting::{Ante, BettingStructure};
+use crate::chips::Chips;
+use crate::rake::Rake;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Everything needed to start a table. Build one up from the defaults, e.g.
+// `GameConfig::default().blinds(Chips::new(50), Chips::new(100)).player_count(6)`,
+// or load it from a TOML or JSON file where any setting left out keeps its default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    pub variant: Variant,
+    pub small_blind: Chips,
+    pub big_blind: Chips,
+    pub starting_stack: Chips,
+    // One seat per name
+    pub players: Vec<String>,
+    pub betting: BettingStructure,
+    pub ante: Ante,
+    pub straddle: bool,
+    pub rake: Rake,
+    // Leave out to deal a different game every time
+    pub seed: Option<u64>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Variant {
+    #[default]
+    TexasHoldem,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            variant: Variant::TexasHoldem,
+            small_blind: Chips::new(25),
+            big_blind: Chips::new(50),
+            starting_stack: Chips::from_dollars(100),
+            players: default_names(4),
+            betting: BettingStructure::NoLimit,
+            ante: Ante::None,
+            straddle: false,
+            rake: Rake::default(),
+            seed: None,
+        }
+    }
+}
+
+fn default_names(count: u8) -> Vec<String> {
+    (0..count).map(|i| format!("Player {}", i)).collect()
+}
+
+impl GameConfig {
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn blinds(mut self, small_blind: Chips, big_blind: Chips) -> Self {
+        self.small_blind = small_blind;
+        self.big_blind = big_blind;
+        self
+    }
+
+    pub fn starting_stack(mut self, stack: Chips) -> Self {
+        self.starting_stack = stack;
+        self
+    }
+
+    pub fn players<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.players = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    // Seats `count` players named "Player 0", "Player 1" and so on
+    pub fn player_count(mut self, count: u8) -> Self {
+        self.players = default_names(count);
+        self
+    }
+
+    pub fn betting(mut self, betting: BettingStructure) -> Self {
+        self.betting = betting;
+        self
+    }
+
+    pub fn ante(mut self, ante: Ante) -> Self {
+        self.ante = ante;
+        self
+    }
+
+    pub fn straddle(mut self, straddle: bool) -> Self {
+        self.straddle = straddle;
+        self
+    }
+
+    pub fn rake(mut self, rake: Rake) -> Self {
+        self.rake = rake;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    // Reads a config file, picking TOML or JSON from the file's extension
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<GameConfig, ConfigError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => GameConfig::from_toml(&contents),
+            Some("json") => GameConfig::from_json(&contents),
+            _ => Err(ConfigError::UnknownFormat(path.display().to_string())),
+        }
+    }
+
+    pub fn from_toml(contents: &str) -> Result<GameConfig, ConfigError> {
+        let config: GameConfig = toml::from_str(contents).map_err(|err| ConfigError::Parse(err.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn from_json(contents: &str) -> Result<GameConfig, ConfigError> {
+        let config: GameConfig = serde_json::from_str(contents).map_err(|err| ConfigError::Parse(err.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    // Checks the settings make a playable table
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.players.len() < 2 {
+            return Err(ConfigError::Invalid(String::from("a table needs at least two players")));
+        }
+        if self.players.len() > u8::MAX as usize {
+            return Err(ConfigError::Invalid(format!("a table seats at most {} players", u8::MAX)));
+        }
+        if self.small_blind.is_zero() || self.big_blind < self.small_blind {
+            return Err(ConfigError::Invalid(String::from("the big blind has to be at least the small blind, which cannot be zero")));
+        }
+        if self.starting_stack.is_zero() {
+            return Err(ConfigError::Invalid(String::from("players have to start with chips")));
+        }
+        if let BettingStructure::FixedLimit { small_bet, big_bet, raise_cap } = self.betting {
+            if small_bet.is_zero() || big_bet.is_zero() || raise_cap == 0 {
+                return Err(ConfigError::Invalid(String::from("fixed limit bets and the raise cap cannot be zero")));
+            }
+        }
+        if self.rake.percent > 100 {
+            return Err(ConfigError::Invalid(String::from("the rake cannot be more than the whole pot")));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    UnknownFormat(String),
+    Parse(String),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "unable to read config: {}", err),
+            ConfigError::UnknownFormat(path) => write!(f, "{} is not a .toml or .json file", path),
+            ConfigError::Parse(err) => write!(f, "unable to parse config: {}", err),
+            ConfigError::Invalid(reason) => write!(f, "invalid config: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -1,11 +1,40 @@
+use crate::render::{self, Colors};
 use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
 use std::io::Write;
+use std::ops::{BitAnd, BitOr, Not};
+use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Community {
     pub cards: [Card; 5],
 }
 
+// Reads a board of up to five cards, like "Qc Jc Tc". Cards not given are
+// left undealt.
+impl FromStr for Community {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Community, CardParseError> {
+        let cards = parse_cards(s)?;
+        let mut community = Community::new();
+        if cards.len() > community.cards.len() {
+            return Err(CardParseError::TooManyCards { max: community.cards.len(), found: cards.len() });
+        }
+        community.cards[..cards.len()].copy_from_slice(&cards);
+        Ok(community)
+    }
+}
+
+impl Default for Community {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Community {
     pub fn new() -> Community {
         Community {
@@ -47,22 +76,18 @@ impl Community {
     pub fn print<W>(&self, mut w: W) 
     where W: Write
     {
-        let mut cards = String::new();
-        for card in self.cards.iter() {
-            cards.push_str(&format!("{:?} ", card));
-        }
-        writeln!(w, "Community: {}", cards).unwrap();
+        writeln!(w, "Community: {}", render::cards(&self.cards, Colors::None)).unwrap();
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
 pub enum Suit {
     None,
     Clubs,
@@ -71,7 +96,7 @@ pub enum Suit {
     Spades,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
 pub enum Rank {
     None,
     Two,
@@ -89,6 +114,185 @@ pub enum Rank {
     Ace,
 }
 
+// Cards are written as rank then suit, like "As", "Td" or "7h". An undealt
+// card, with no rank or suit, is written "??".
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)
+    }
+}
+
+impl FromStr for Card {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Card, CardParseError> {
+        let invalid = || CardParseError::InvalidCard(s.to_string());
+        let mut chars = s.chars();
+        let (Some(rank), Some(suit), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(invalid());
+        };
+        let rank = Rank::from_char(rank).ok_or_else(invalid)?;
+        let suit = Suit::from_char(suit).ok_or_else(invalid)?;
+        Ok(Card { suit, rank })
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Suit::None => '?',
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl FromStr for Suit {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Suit, CardParseError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Suit::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| CardParseError::InvalidCard(s.to_string()))
+    }
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    fn from_char(c: char) -> Option<Suit> {
+        match c.to_ascii_lowercase() {
+            'c' => Some(Suit::Clubs),
+            'd' => Some(Suit::Diamonds),
+            'h' => Some(Suit::Hearts),
+            's' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Rank::None => '?',
+            Rank::Two => '2',
+            Rank::Three => '3',
+            Rank::Four => '4',
+            Rank::Five => '5',
+            Rank::Six => '6',
+            Rank::Seven => '7',
+            Rank::Eight => '8',
+            Rank::Nine => '9',
+            Rank::Ten => 'T',
+            Rank::Jack => 'J',
+            Rank::Queen => 'Q',
+            Rank::King => 'K',
+            Rank::Ace => 'A',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl FromStr for Rank {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Rank, CardParseError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Rank::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| CardParseError::InvalidCard(s.to_string()))
+    }
+}
+
+impl Rank {
+    // Lowest to highest
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    fn from_char(c: char) -> Option<Rank> {
+        match c.to_ascii_uppercase() {
+            '2' => Some(Rank::Two),
+            '3' => Some(Rank::Three),
+            '4' => Some(Rank::Four),
+            '5' => Some(Rank::Five),
+            '6' => Some(Rank::Six),
+            '7' => Some(Rank::Seven),
+            '8' => Some(Rank::Eight),
+            '9' => Some(Rank::Nine),
+            'T' => Some(Rank::Ten),
+            'J' => Some(Rank::Jack),
+            'Q' => Some(Rank::Queen),
+            'K' => Some(Rank::King),
+            'A' => Some(Rank::Ace),
+            _ => None,
+        }
+    }
+}
+
+// Why a string of cards could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardParseError {
+    InvalidCard(String),
+    Duplicate(Card),
+    WrongCount { expected: usize, found: usize },
+    TooManyCards { max: usize, found: usize },
+}
+
+impl fmt::Display for CardParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardParseError::InvalidCard(card) => write!(f, "{} is not a card", card),
+            CardParseError::Duplicate(card) => write!(f, "{} is given more than once", card),
+            CardParseError::WrongCount { expected, found } => write!(f, "expected {} cards, found {}", expected, found),
+            CardParseError::TooManyCards { max, found } => write!(f, "expected at most {} cards, found {}", max, found),
+        }
+    }
+}
+
+impl Error for CardParseError {}
+
+// Parses cards written back to back like "AsKd", apart like "Qc Jc Tc", or a
+// mix of both. The same card may not be given twice.
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, CardParseError> {
+    let mut cards: Vec<Card> = Vec::new();
+    let mut seen = CardSet::EMPTY;
+    for token in s.split_whitespace() {
+        let chars: Vec<char> = token.chars().collect();
+        if !chars.len().is_multiple_of(2) {
+            return Err(CardParseError::InvalidCard(token.to_string()));
+        }
+        for pair in chars.chunks(2) {
+            let card: Card = pair.iter().collect::<String>().parse()?;
+            if !seen.insert(card) {
+                return Err(CardParseError::Duplicate(card));
+            }
+            cards.push(card);
+        }
+    }
+    Ok(cards)
+}
+
 #[derive(Copy, Clone, Debug)]
 #[allow(dead_code)]
 pub struct Deck {
@@ -96,6 +300,12 @@ pub struct Deck {
     pub dealt: usize,
 }
 
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Deck {
     pub fn new() -> Deck {
         let mut cards = [Card {
@@ -135,7 +345,13 @@ impl Deck {
     }
 
     pub fn shuffle(&mut self) {
-        self.cards.shuffle(&mut rand::thread_rng());
+        self.shuffle_with(&mut rand::thread_rng());
+    }
+
+    // Shuffles with the given random number generator, so a seeded generator
+    // deals the same cards every time.
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
         self.dealt = 0;
     }
 
@@ -148,4 +364,181 @@ impl Deck {
     pub fn discard(&mut self) {
         self.dealt += 1;
     }
+
+    // The cards still to be dealt
+    pub fn undealt(&self) -> CardSet {
+        self.cards[self.dealt.min(self.cards.len())..].iter().copied().collect()
+    }
+}
+
+// A set of cards packed into 64 bits. Each suit takes 16 bits, clubs lowest,
+// and within a suit bit 0 is the two and bit 12 the ace. Undealt cards, with
+// no rank or suit, are never in a set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(u64);
+
+const RANK_BITS: u64 = 0x1fff;
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const FULL: CardSet = CardSet(RANK_BITS | RANK_BITS << 16 | RANK_BITS << 32 | RANK_BITS << 48);
+
+    pub fn from_bits(bits: u64) -> CardSet {
+        CardSet(bits & CardSet::FULL.0)
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & CardSet::bit(card) != 0
+    }
+
+    // False when the card was already in the set or is undealt
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = CardSet::bit(card);
+        let added = bit != 0 && self.0 & bit == 0;
+        self.0 |= bit;
+        added
+    }
+
+    // False when the card was not in the set
+    pub fn remove(&mut self, card: Card) -> bool {
+        let bit = CardSet::bit(card);
+        let removed = self.0 & bit != 0;
+        self.0 &= !bit;
+        removed
+    }
+
+    pub fn union(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    // The cards in this set that are not in `other`
+    pub fn difference(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    // The ranks held in `suit`, bit 0 for the two up to bit 12 for the ace
+    pub fn suit_mask(&self, suit: Suit) -> u16 {
+        match CardSet::suit_index(suit) {
+            Some(index) => (self.0 >> (16 * index) & RANK_BITS) as u16,
+            None => 0,
+        }
+    }
+
+    // The ranks held in any suit, laid out like `suit_mask`
+    pub fn rank_mask(&self) -> u16 {
+        Suit::ALL.iter().fold(0, |mask, &suit| mask | self.suit_mask(suit))
+    }
+
+    // Cards from the two of clubs up to the ace of spades
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    fn suit_index(suit: Suit) -> Option<u32> {
+        match suit {
+            Suit::None => None,
+            Suit::Clubs => Some(0),
+            Suit::Diamonds => Some(1),
+            Suit::Hearts => Some(2),
+            Suit::Spades => Some(3),
+        }
+    }
+
+    fn bit(card: Card) -> u64 {
+        match (CardSet::suit_index(card.suit), card.rank) {
+            (None, _) | (_, Rank::None) => 0,
+            // `Rank::Two` is 1 as `Rank::None` comes first
+            (Some(suit), rank) => 1 << (16 * suit + rank as u32 - 1),
+        }
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> CardSet {
+        CardSet(CardSet::bit(card))
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> CardSet {
+        let mut set = CardSet::EMPTY;
+        for card in cards {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    // Every card not in the set
+    fn not(self) -> CardSet {
+        CardSet::FULL.difference(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(Card {
+            suit: Suit::ALL[bit / 16],
+            rank: Rank::ALL[bit % 16],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
 }
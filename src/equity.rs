@@ -0,0 +1,167 @@
+use crate::deck::{Card, CardSet, Deck, Rank, Suit};
+use crate::evaluator::{evaluate, HandValue};
+use rand::Rng;
+use std::error::Error;
+use std::fmt;
+
+// How one hand did over every runout that was dealt
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct HandEquity {
+    pub wins: u64,
+    // Runouts where this hand split the pot with others
+    pub ties: u64,
+    pub losses: u64,
+    // Pots won, counting a split between n hands as 1/n of a pot
+    pub pots: f64,
+}
+
+impl HandEquity {
+    pub fn runouts(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    pub fn win_percent(&self) -> f64 {
+        self.percent(self.wins as f64)
+    }
+
+    pub fn tie_percent(&self) -> f64 {
+        self.percent(self.ties as f64)
+    }
+
+    pub fn loss_percent(&self) -> f64 {
+        self.percent(self.losses as f64)
+    }
+
+    // The share of the pot this hand is due, ties split evenly
+    pub fn equity_percent(&self) -> f64 {
+        self.percent(self.pots)
+    }
+
+    fn percent(&self, count: f64) -> f64 {
+        match self.runouts() {
+            0 => 0.0,
+            runouts => count * 100.0 / runouts as f64,
+        }
+    }
+}
+
+// Why the equity of a set of hands could not be worked out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquityError {
+    TooFewHands,
+    TooManyBoardCards(usize),
+    UndealtCard,
+    Duplicate(Card),
+    // More hands than there are cards to deal them
+    NotEnoughCards,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EquityError::TooFewHands => write!(f, "at least two hands are needed"),
+            EquityError::TooManyBoardCards(count) => write!(f, "a board has at most 5 cards, {} were given", count),
+            EquityError::UndealtCard => write!(f, "every card needs a rank and a suit"),
+            EquityError::Duplicate(card) => write!(f, "{} is given more than once", card),
+            EquityError::NotEnoughCards => write!(f, "there are not enough cards left to finish the board"),
+        }
+    }
+}
+
+impl Error for EquityError {}
+
+// Deals `trials` random runouts of the board to `hands` and reports how each
+// hand did, in the order given. `dead` cards are known to be out of the deck,
+// like folded or burned cards.
+pub fn monte_carlo<R: Rng + ?Sized>(
+    hands: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    trials: u64,
+    rng: &mut R,
+) -> Result<Vec<HandEquity>, EquityError> {
+    let used = check(hands, board, dead)?;
+    let known: CardSet = board.iter().copied().collect();
+    let mut results = vec![HandEquity::default(); hands.len()];
+    let mut deck = Deck::new();
+    for _ in 0..trials {
+        deck.shuffle_with(rng);
+        let mut runout = known;
+        while runout.len() < 5 {
+            let card = deck.deal();
+            if !used.contains(card) {
+                runout.insert(card);
+            }
+        }
+        tally(&mut results, hands, runout);
+    }
+    Ok(results)
+}
+
+// Deals every possible runout of the board instead of sampling. From the flop
+// on this is quick, preflop it is close to two million runouts.
+pub fn exhaustive(hands: &[[Card; 2]], board: &[Card], dead: &[Card]) -> Result<Vec<HandEquity>, EquityError> {
+    let used = check(hands, board, dead)?;
+    let known: CardSet = board.iter().copied().collect();
+    let remaining: Vec<Card> = (!used).iter().collect();
+    let missing = 5 - board.len();
+    let mut results = vec![HandEquity::default(); hands.len()];
+
+    // Walks every combination of `missing` of the remaining cards in order
+    let mut picks: Vec<usize> = (0..missing).collect();
+    loop {
+        let runout = picks.iter().fold(known, |set, &i| set | CardSet::from(remaining[i]));
+        tally(&mut results, hands, runout);
+
+        let Some(slot) = (0..missing).rev().find(|&slot| picks[slot] < remaining.len() - missing + slot) else {
+            break;
+        };
+        picks[slot] += 1;
+        for next in slot + 1..missing {
+            picks[next] = picks[next - 1] + 1;
+        }
+    }
+    Ok(results)
+}
+
+// All the cards in play, after making sure they can be dealt
+fn check(hands: &[[Card; 2]], board: &[Card], dead: &[Card]) -> Result<CardSet, EquityError> {
+    if hands.len() < 2 {
+        return Err(EquityError::TooFewHands);
+    }
+    if board.len() > 5 {
+        return Err(EquityError::TooManyBoardCards(board.len()));
+    }
+    let mut used = CardSet::EMPTY;
+    for &card in hands.iter().flatten().chain(board.iter()).chain(dead.iter()) {
+        if card.rank == Rank::None || card.suit == Suit::None {
+            return Err(EquityError::UndealtCard);
+        }
+        if !used.insert(card) {
+            return Err(EquityError::Duplicate(card));
+        }
+    }
+    if 52 - used.len() < 5 - board.len() {
+        return Err(EquityError::NotEnoughCards);
+    }
+    Ok(used)
+}
+
+fn tally(results: &mut [HandEquity], hands: &[[Card; 2]], board: CardSet) {
+    let values: Vec<HandValue> = hands.iter()
+        .map(|hand| evaluate(board | CardSet::from(hand[0]) | CardSet::from(hand[1])))
+        .collect();
+    let best = values.iter().max().copied().unwrap_or_default();
+    let winners = values.iter().filter(|&&value| value == best).count();
+    for (result, value) in results.iter_mut().zip(values.iter()) {
+        if *value != best {
+            result.losses += 1;
+        } else if winners == 1 {
+            result.wins += 1;
+            result.pots += 1.0;
+        } else {
+            result.ties += 1;
+            result.pots += 1.0 / winners as f64;
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::chips::Chips;
+use crate::deck::Card;
+use crate::hand::BestHand;
+use crate::player::PlayerAction;
+use serde::{Deserialize, Serialize};
+
+// A subscriber to the events of a `Game`
+pub type Observer = Box<dyn FnMut(&GameEvent)>;
+
+// Everything that happens at the table, in the order it happens. Seats are
+// indexes into the players listed by the `HandStarted` event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameEvent {
+    HandStarted { hand_number: u64, button: usize, players: Vec<(String, Chips)> },
+    BlindPosted { seat: usize, kind: ForcedBet, amount: Chips },
+    HoleCardsDealt { seat: usize, cards: [Card; 2] },
+    CommunityDealt { street: Street, cards: Vec<Card> },
+    StreetAdvanced { street: Street },
+    // `amount` is what the action put into the pot
+    PlayerActed { seat: usize, action: PlayerAction, amount: Chips },
+    RakeTaken { amount: Chips },
+    // `hand` is only shown when the pot went to showdown
+    PotAwarded { seat: usize, amount: Chips, hand: Option<BestHand> },
+    PlayerBusted { seat: usize },
+    TableWon { seat: usize, name: String },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Street {
+    PreFlop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForcedBet {
+    SmallBlind,
+    BigBlind,
+    Ante,
+    // One ante for the whole table, posted by the big blind
+    BigBlindAnte,
+    Straddle,
+    // The ante everyone posts in a bomb pot
+    BombPot,
+}
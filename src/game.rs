@@ -1,8 +1,18 @@
-use crate::deck::{Deck, Community, Rank};
-use crate::hand::ScoringHands;
-use crate::player::{Player, PlayerAction, self};
+use crate::betting::{Ante, BettingStructure};
+use crate::chips::Chips;
+use crate::config::GameConfig;
+use crate::deck::{Card, Deck, Community, Rank};
+use crate::event::{ForcedBet, GameEvent, Observer, Street};
+use crate::hand::{BestHand, HandRank};
+use crate::player::{Player, PlayerAction};
+use crate::rake::Rake;
+use crate::strategy::{LegalActions, Prompt, Strategy, TableView};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
+use std::error::Error;
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver};
 
 pub struct Game {
     players: Vec<Player>,
@@ -10,11 +20,114 @@ pub struct Game {
     community: Community,
     turn: usize,
     state: GameState,
-    pot: f32,
-    current_bid: f32,
-    blind: f32,
+    pot: Chips,
+    current_bid: Chips,
+    // The size of the last full raise on this street, the big blind until then
+    last_raise: Chips,
+    // Bets and raises made on this street, including the big blind before the flop
+    bets: u32,
+    small_blind: Chips,
+    big_blind: Chips,
+    structure: BettingStructure,
+    ante: Ante,
+    // Whether under the gun posts a live straddle of two big blinds
+    straddle: bool,
+    // The ante everyone posts when the next hand is a bomb pot
+    bomb_pot: Option<Chips>,
+    rake: Rake,
+    // Everything the house has taken, kept apart from the players' banks
+    rake_collected: Chips,
+    positions: Positions,
+    // Seats that still have to act before the betting round closes
+    needs_action: Vec<bool>,
+    // Seats that acted since the last full raise. An all in for less than a full
+    // raise does not let them raise again.
+    acted: Vec<bool>,
+    hand_number: u64,
+    seed: u64,
+    rng: StdRng,
+    observers: Vec<Observer>,
 }
 
+// What came of a call to `Game::play_hand`
+#[derive(Debug, Clone)]
+pub enum GameResult {
+    HandFinished(HandResult),
+    TableWon { seat: usize, name: String },
+    // Too many players are sitting out to deal a hand
+    Waiting,
+}
+
+#[derive(Debug, Clone)]
+pub struct HandResult {
+    pub community: Community,
+    pub payouts: Vec<Payout>,
+    // Taken out of the pot before it was paid out
+    pub rake: Chips,
+}
+
+#[derive(Debug, Clone)]
+pub struct Payout {
+    pub seat: usize,
+    pub name: String,
+    pub amount: Chips,
+    // Not shown when everybody else folded
+    pub hand: Option<BestHand>,
+}
+
+// Seats for the current hand, derived from the button
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Positions {
+    pub button: usize,
+    pub small_blind: usize,
+    pub big_blind: usize,
+    pub under_the_gun: usize,
+}
+
+// Why `Game::apply_action` refused an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionError {
+    NoHandInProgress,
+    OutOfTurn { seat: usize, to_act: usize },
+    IllegalAction(PlayerAction),
+    RaiseTooSmall { amount: Chips, min_raise: Chips },
+    RaiseTooLarge { amount: Chips, max_raise: Chips },
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ActionError::NoHandInProgress => write!(f, "no hand is in progress"),
+            ActionError::OutOfTurn { seat, to_act } => write!(f, "seat {} acted out of turn, seat {} is to act", seat, to_act),
+            ActionError::IllegalAction(action) => write!(f, "{:?} is not a legal action", action),
+            ActionError::RaiseTooSmall { amount, min_raise } => write!(f, "raise of {} is less than the minimum raise of {}", amount, min_raise),
+            ActionError::RaiseTooLarge { amount, max_raise } => write!(f, "raise of {} is more than the maximum raise of {}", amount, max_raise),
+        }
+    }
+}
+
+impl Error for ActionError {}
+
+// Why a player could not join or leave the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatError {
+    HandInProgress,
+    TableFull,
+    NoSuchSeat(usize),
+}
+
+impl fmt::Display for SeatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeatError::HandInProgress => write!(f, "players can only join or leave between hands"),
+            SeatError::TableFull => write!(f, "the table is full"),
+            SeatError::NoSuchSeat(seat) => write!(f, "there is no seat {}", seat),
+        }
+    }
+}
+
+impl Error for SeatError {}
+
 enum GameState {
     PreFlop,
     Flop,
@@ -26,16 +139,48 @@ enum GameState {
 
 impl Game {
     pub fn new(player_count: u8) -> Game {
-        // Initialize players
-        let mut players: Vec<Player> = Vec::new();
-        for i in 0..player_count {
-            players.push(Player::new(i, format!("Player {}", i), None));
-        }
-        players.shuffle(&mut thread_rng());
+        Game::from_config(&GameConfig::default().player_count(player_count))
+    }
+
+    // Seats a human answering prompts on stdin for every player in the config
+    pub fn from_config(config: &GameConfig) -> Game {
+        Game::from_config_with(config, |_| Box::new(Prompt::stdio()))
+    }
+
+    // Same as `from_config`, but `strategy` picks how each player, given by name,
+    // decides
+    pub fn from_config_with<F>(config: &GameConfig, mut strategy: F) -> Game
+    where
+        F: FnMut(&str) -> Box<dyn Strategy>,
+    {
+        let players = config.players.iter().enumerate()
+            .map(|(id, name)| Player::with_strategy(id as u8, name.clone(), Some(config.starting_stack), strategy(name)))
+            .collect();
+        let mut game = Game::with_seed(players, config.seed.unwrap_or_else(|| thread_rng().gen()));
+        game.small_blind = config.small_blind;
+        game.big_blind = config.big_blind;
+        game.structure = config.betting;
+        game.ante = config.ante;
+        game.straddle = config.straddle;
+        game.rake = config.rake;
+        game
+    }
+
+    // Seats the given players, each deciding with their own strategy
+    pub fn with_players(players: Vec<Player>) -> Game {
+        Game::with_seed(players, thread_rng().gen())
+    }
+
+    // Same as `with_players`, but seating and every shuffle are driven by `seed`
+    // so the same seed and decisions replay the same game.
+    pub fn with_seed(mut players: Vec<Player>, seed: u64) -> Game {
+        let mut rng = StdRng::seed_from_u64(seed);
+        players.shuffle(&mut rng);
+        let count = players.len().max(1);
+        let defaults = GameConfig::default();
 
         // Initialize deck
-        let mut deck = Deck::new();
-        deck.shuffle();
+        let deck = Deck::new();
 
         // Initialize community
         let community = Community::new();
@@ -44,164 +189,498 @@ impl Game {
             players,
             deck,
             turn: 0,
-            state: GameState::PreFlop,
-            pot: 0.0,
-            blind: 0.25,
-            current_bid: 0.0,
+            state: GameState::Showdown,
+            pot: Chips::ZERO,
+            small_blind: defaults.small_blind,
+            big_blind: defaults.big_blind,
+            structure: defaults.betting,
+            ante: defaults.ante,
+            straddle: defaults.straddle,
+            bomb_pot: None,
+            rake: defaults.rake,
+            rake_collected: Chips::ZERO,
+            current_bid: Chips::ZERO,
+            last_raise: Chips::ZERO,
+            bets: 0,
             community,
+            // The first hand moves the button onto the first seat
+            positions: Positions {
+                button: count - 1,
+                small_blind: 0,
+                big_blind: 0,
+                under_the_gun: 0,
+            },
+            needs_action: Vec::new(),
+            acted: Vec::new(),
+            hand_number: 0,
+            seed,
+            rng,
+            observers: Vec::new(),
         }
     }
 
-    pub fn start(&mut self) {
-        self.state = GameState::Showdown;
-        self.advance_state();
-        self.loop_turns();
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn betting_structure(&self) -> BettingStructure {
+        self.structure
+    }
+
+    // Takes effect from the next bet on
+    pub fn set_betting_structure(&mut self, structure: BettingStructure) {
+        self.structure = structure;
+    }
+
+    // Blinds, antes and straddles take effect from the next hand on
+    pub fn set_blinds(&mut self, small_blind: Chips, big_blind: Chips) {
+        self.small_blind = small_blind;
+        self.big_blind = big_blind;
+    }
+
+    pub fn set_ante(&mut self, ante: Ante) {
+        self.ante = ante;
+    }
+
+    pub fn set_straddle(&mut self, straddle: bool) {
+        self.straddle = straddle;
+    }
+
+    // Takes effect from the next pot on
+    pub fn set_rake(&mut self, rake: Rake) {
+        self.rake = rake;
+    }
+
+    pub fn rake_collected(&self) -> Chips {
+        self.rake_collected
+    }
+
+    // Makes the next hand a bomb pot. Instead of the blinds and antes everyone
+    // dealt in posts `ante`, and the hand starts on the flop.
+    pub fn schedule_bomb_pot(&mut self, ante: Chips) {
+        self.bomb_pot = Some(ante);
+    }
+
+    // Calls `observer` with every event that happens at the table from now on
+    pub fn subscribe<F>(&mut self, observer: F)
+    where
+        F: FnMut(&GameEvent) + 'static,
+    {
+        self.observers.push(Box::new(observer));
     }
 
-    fn get_active_players(&self) -> Vec<&Player> {
-        let result = self.players.iter().filter(|player| player.is_active()).collect();
-        result
+    // Same as `subscribe`, but delivers the events through a channel
+    pub fn event_channel(&mut self) -> Receiver<GameEvent> {
+        let (sender, receiver) = channel();
+        self.subscribe(move |event| {
+            // Nobody listening anymore is not an error for the game
+            let _ = sender.send(event.clone());
+        });
+        receiver
     }
 
-    fn payout_player_idx(&mut self, player_idx: usize) {
-        self.players[player_idx].bank += self.pot;
-        self.pot = 0.0;
+    fn emit(&mut self, event: GameEvent) {
+        for observer in self.observers.iter_mut() {
+            observer(&event);
+        }
     }
 
-    fn advance_state(&mut self) {
+    // Plays one full hand and returns control to the caller. A hand already in
+    // progress is played out instead of dealing a new one. Once a single player
+    // has chips left the table is won and no more hands are dealt.
+    pub fn play_hand(&mut self) -> GameResult {
+        if let Some(result) = self.start_hand() {
+            return result;
+        }
+        loop {
+            let seat = self.to_act().expect("a hand in progress always has a player to act");
+            let view = self.table_view(seat);
+            let (action, amount) = self.players[seat].strategy.decide(&view);
+            if let Some(result) = self.apply_decision(seat, action, amount) {
+                return GameResult::HandFinished(result);
+            }
+        }
+    }
+
+    // The seat whose turn it is, or None when no hand is in progress
+    pub fn to_act(&self) -> Option<usize> {
         match self.state {
-            GameState::PreFlop => {
-                println!("\n\n\nStarting Flop");
-                self.state = GameState::Flop;
-                self.deal_community(3);
-                self.community.print(std::io::stdout());
+            GameState::PreFlop | GameState::Flop | GameState::Turn | GameState::River => self.next_to_act(),
+            GameState::Showdown | GameState::Closed => None,
+        }
+    }
 
-                 // find active players
-                let active_players = self.get_active_players();
-                let active_player_count = active_players.len();
+    // The next seat, starting from `turn`, that still owes action this betting
+    // round. None once the round is closed or only one player is left in the hand.
+    fn next_to_act(&self) -> Option<usize> {
+        if self.players.iter().filter(|player| player.in_hand()).count() <= 1 {
+            return None;
+        }
+        let count = self.players.len();
+        (0..count).map(|offset| (self.turn + offset) % count).find(|&seat| self.needs_action[seat])
+    }
 
-                for player in self.players.iter_mut() {
-                    if player.is_active() {
-                        player.last_action = PlayerAction::None;
-                    }
-                }
+    // Gives every player who can still bet the action for a new betting round. When
+    // nobody else can bet, a player only has to act if they have a bet to call.
+    fn open_action(&mut self) {
+        let betting = self.players.iter().filter(|player| player.is_active()).count() > 1;
+        self.needs_action = self.players.iter()
+            .map(|player| player.is_active() && (betting || player.bid < self.current_bid))
+            .collect();
+        self.acted = vec![false; self.players.len()];
+    }
 
-                if active_player_count == 1 {
-                    // Game over - Declare table winner
-                    self.state = GameState::River;
-                    return self.advance_state();
-                }
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    // The player in `seat`, only between hands so a hand in progress cannot be
+    // tampered with
+    pub fn player_mut(&mut self, seat: usize) -> Result<&mut Player, SeatError> {
+        if self.to_act().is_some() {
+            return Err(SeatError::HandInProgress);
+        }
+        self.players.get_mut(seat).ok_or(SeatError::NoSuchSeat(seat))
+    }
+
+    // Deals out streets until somebody has to act. Returns the result if the hand
+    // ends first.
+    fn settle(&mut self) -> Option<HandResult> {
+        while self.next_to_act().is_none() {
+            if let Some(result) = self.advance_state() {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    // Moves the hand on to its next street. Returns the result once the hand is over.
+    fn advance_state(&mut self) -> Option<HandResult> {
+        let in_hand_count = self.players.iter().filter(|player| player.in_hand()).count();
+        if in_hand_count == 1 && !matches!(self.state, GameState::Showdown | GameState::Closed) {
+            // Everyone else folded
+            return Some(self.showdown());
+        }
 
-                self.turn = 0;
+        match self.state {
+            GameState::PreFlop => {
+                self.state = GameState::Flop;
+                self.new_street(Street::Flop, 3);
             },
             GameState::Flop => {
-                println!("\n\n\nStarting Turn");
                 self.state = GameState::Turn;
-                self.deal_community(1);
-                self.turn = 0;
-
-                // find active players
-                let active_players = self.get_active_players();
-                let active_player_count = active_players.len();
-                if active_player_count == 1 {
-                    // Game over - Declare table winner
-                    self.state = GameState::River;
-                    return self.advance_state();
-                }
-
-                for player in self.players.iter_mut() {
-                    if player.is_active() {
-                        player.last_action = PlayerAction::None;
-                    }
-                }
+                self.new_street(Street::Turn, 1);
             },
             GameState::Turn => {
-                println!("\n\n\nStarting River");
                 self.state = GameState::River;
-                self.deal_community(1);
-
-                 // find active players
-                let active_players = self.get_active_players();
-                let active_player_count = active_players.len();
-                if active_player_count == 1 {
-                    // Game over - Declare table winner
-                    self.state = GameState::River;
-                    return self.advance_state();
-                }
-
-                for player in self.players.iter_mut() {
-                    if player.is_active() {
-                        player.last_action = PlayerAction::None;
-                    }
-                }
-
-                self.turn = 0;
+                self.new_street(Street::River, 1);
             },
             GameState::River => {
-                println!("\n\n\nStarting Showdown");
-                self.state = GameState::Showdown;
-                // Determine winner
-                let active_players = self.get_active_players();
-                let mut active_player_hand_values: Vec<(ScoringHands, u8, usize)> = Vec::new();
-                for (idx, player) in active_players.iter().enumerate() {
-                    let mut hand = player.hand;
-                    let value = hand.hand_value(&self.community);
-                    active_player_hand_values.push((value.0, value.1, idx));
-                }
-                // TODO: fix the sorting
-                active_player_hand_values.sort_by(|a, b| b.partial_cmp(a).unwrap());
-                let winner_idx = active_player_hand_values[0].2;
-                println!("Winner: {}", self.players[winner_idx].name);
-                println!("Hand: {:?}", self.players[winner_idx].hand);
-                self.payout_player_idx(winner_idx);
-            },
-            GameState::Showdown => {
-                println!("\n\n\nStarting PreFlop");
-                self.state = GameState::PreFlop;
-                let active_players = self.get_active_players();
-                let active_player_count = active_players.len();
-                if active_player_count == 1 {
-                    // Game over - Declare table winner
-                    self.state = GameState::Closed;
-                    return self.advance_state();
-                }
-                self.deck.shuffle();
-                self.community.reset();
-                self.community.print(std::io::stdout());
-                self.current_bid = 0.0;
-                self.pot = 0.0;
-                // Shift the player order by 1
-                let first_player = self.players.remove(0);
-                self.players.push(first_player);
-                for player in self.players.iter_mut() {
-                    if player.is_active() {
-                        player.reset();
-                        player.hand.fill(&mut self.deck);
-                    }
-                    if self.pot == 0.0 {
-                        // Collect small blind
-                        self.pot += player.blind(self.blind);
-                    } else if self.pot < self.blind * 3.0 {
-                        // Collect big blind
-                        self.pot += player.blind(self.blind * 2.0);
-                    }
-                }
-                self.turn = 2;
-                if active_player_count == 2 {
-                    self.turn = 0;
-                }
-            },
-            GameState::Closed => {
-                let active_players = self.get_active_players();
-                println!("\n\n\nTable Winner: {}", active_players[0].name);
-                println!("Thanks for playing!");
-                std::process::exit(0);
+                return Some(self.showdown());
             },
+            GameState::Showdown | GameState::Closed => unreachable!("no hand in progress"),
+        }
+        None
+    }
+
+    // Deals the next hand and leaves it waiting on the first player to act, see
+    // `to_act`. Returns the result instead when the table is already won or the
+    // hand ends without anyone having to act. Does nothing while a hand is still
+    // in progress.
+    pub fn start_hand(&mut self) -> Option<GameResult> {
+        if self.to_act().is_some() {
+            return None;
+        }
+        let funded: Vec<usize> = (0..self.players.len()).filter(|&idx| !self.players[idx].bank.is_zero()).collect();
+        if self.players.iter().filter(|player| player.can_play()).count() <= 1 && funded.len() > 1 {
+            return Some(GameResult::Waiting);
+        }
+        if funded.len() <= 1 {
+            self.state = GameState::Closed;
+            let seat = funded.first().copied().unwrap_or(0);
+            let name = self.players[seat].name.clone();
+            self.emit(GameEvent::TableWon { seat, name: name.clone() });
+            return Some(GameResult::TableWon { seat, name });
+        }
+
+        self.state = GameState::PreFlop;
+        for player in self.players.iter_mut() {
+            player.reset();
+        }
+        self.deck.shuffle_with(&mut self.rng);
+        self.community.reset();
+        self.current_bid = Chips::ZERO;
+        self.pot = Chips::ZERO;
+
+        self.positions = self.next_positions();
+        let Positions { button, small_blind, big_blind, .. } = self.positions;
+
+        self.hand_number += 1;
+        let players = self.players.iter().map(|player| (player.name.clone(), player.bank)).collect();
+        self.emit(GameEvent::HandStarted { hand_number: self.hand_number, button, players });
+        self.emit(GameEvent::StreetAdvanced { street: Street::PreFlop });
+
+        let mut events: Vec<GameEvent> = Vec::new();
+        let mut dealt: Vec<usize> = Vec::new();
+        let count = self.players.len();
+        for seat in (1..=count).map(|offset| (button + offset) % count) {
+            let player = &mut self.players[seat];
+            if !player.can_play() {
+                // Busted players and players sitting out are not dealt in
+                continue;
+            }
+            player.hand.fill(&mut self.deck);
+            events.push(GameEvent::HoleCardsDealt { seat, cards: player.hand.cards });
+            dealt.push(seat);
+        }
+
+        if let Some(ante) = self.bomb_pot.take() {
+            for &seat in dealt.iter() {
+                events.push(self.post(seat, ForcedBet::BombPot, ante));
+            }
+            for event in events {
+                self.emit(event);
+            }
+            self.state = GameState::Flop;
+            self.new_street(Street::Flop, 3);
+            return self.settle().map(GameResult::HandFinished);
+        }
+
+        // Antes are posted first, except that the big blind takes priority over
+        // a big blind ante when the big blind is short
+        if let Ante::PerPlayer(ante) = self.ante {
+            for &seat in dealt.iter() {
+                events.push(self.post(seat, ForcedBet::Ante, ante));
+            }
+        }
+        events.push(self.post(small_blind, ForcedBet::SmallBlind, self.small_blind));
+        events.push(self.post(big_blind, ForcedBet::BigBlind, self.big_blind));
+        if let Ante::BigBlind(ante) = self.ante {
+            events.push(self.post(big_blind, ForcedBet::BigBlindAnte, ante));
+        }
+        self.current_bid = self.big_blind;
+        self.last_raise = self.big_blind;
+        self.bets = 1;
+        self.turn = self.positions.under_the_gun;
+        // The straddle is a live raise, so the straddler still acts last
+        if self.straddle && dealt.len() > 2 {
+            let straddler = self.positions.under_the_gun;
+            events.push(self.post(straddler, ForcedBet::Straddle, self.big_blind * 2));
+            // A short straddler is all in for what they had, and everyone else
+            // only has to call that
+            let straddle = self.players[straddler].bid;
+            if straddle > self.current_bid {
+                self.current_bid = straddle;
+                self.bets = 2;
+            }
+            if straddle >= self.big_blind * 2 {
+                self.last_raise = straddle;
+            }
+            self.turn = self.next_seat(straddler);
+        }
+        for event in events {
+            self.emit(event);
+        }
+        self.open_action();
+        self.settle().map(GameResult::HandFinished)
+    }
+
+    // Posts a forced bet for the player in `seat`, as much of it as they can cover.
+    // Blinds and straddles count towards the bid, antes are dead money.
+    fn post(&mut self, seat: usize, kind: ForcedBet, amount: Chips) -> GameEvent {
+        let player = &mut self.players[seat];
+        let amount = match kind {
+            ForcedBet::SmallBlind | ForcedBet::BigBlind | ForcedBet::Straddle => player.blind(amount),
+            ForcedBet::Ante | ForcedBet::BigBlindAnte | ForcedBet::BombPot => player.ante(amount),
+        };
+        self.pot += amount;
+        GameEvent::BlindPosted { seat, kind, amount }
+    }
+
+    // The next seat clockwise from `from` that is dealt in
+    fn next_seat(&self, from: usize) -> usize {
+        let count = self.players.len();
+        (1..=count)
+            .map(|offset| (from + offset) % count)
+            .find(|&seat| self.players[seat].can_play())
+            .unwrap_or(from)
+    }
+
+    pub fn positions(&self) -> Positions {
+        self.positions
+    }
+
+    // Where the next hand will be played from. The button moves one seat and the
+    // blinds follow it; heads up the button posts the small blind and acts first
+    // before the flop.
+    pub fn next_positions(&self) -> Positions {
+        let button = self.next_seat(self.positions.button);
+        let dealt_in = self.players.iter().filter(|player| player.can_play()).count();
+        let small_blind = if dealt_in == 2 { button } else { self.next_seat(button) };
+        let big_blind = self.next_seat(small_blind);
+        Positions {
+            button,
+            small_blind,
+            big_blind,
+            under_the_gun: self.next_seat(big_blind),
+        }
+    }
+
+    // Seats a player between hands. To keep the button fair they sit down where
+    // they post the big blind in the very next hand. Returns their seat.
+    pub fn seat_player(&mut self, player: Player) -> Result<usize, SeatError> {
+        if self.to_act().is_some() {
+            return Err(SeatError::HandInProgress);
+        }
+        if self.players.len() >= u8::MAX as usize {
+            return Err(SeatError::TableFull);
+        }
+        if self.players.is_empty() {
+            self.players.push(player);
+            return Ok(0);
+        }
+        // The positions of the next hand with the new player dealt in, so joining
+        // a heads up table does not put them on the small blind
+        let button = self.next_seat(self.positions.button);
+        let dealt_in = self.players.iter().filter(|player| player.can_play()).count() + usize::from(player.can_play());
+        let small_blind = if dealt_in == 2 { button } else { self.next_seat(button) };
+        let seat = small_blind + 1;
+        self.players.insert(seat, player);
+        // Leave the button one seat before where it goes next. At a heads up table
+        // that is the new player's seat, which is fine as the button moves on from it.
+        let button = if button >= seat { button + 1 } else { button };
+        let count = self.players.len();
+        self.positions.button = (button + count - 1) % count;
+        Ok(seat)
+    }
+
+    // Takes the player in `seat` away from the table between hands. The seats
+    // after theirs move down one, and the button still moves on to the player
+    // after theirs.
+    pub fn remove_player(&mut self, seat: usize) -> Result<Player, SeatError> {
+        if self.to_act().is_some() {
+            return Err(SeatError::HandInProgress);
+        }
+        if seat >= self.players.len() {
+            return Err(SeatError::NoSuchSeat(seat));
+        }
+        let player = self.players.remove(seat);
+        let count = self.players.len().max(1);
+        if seat <= self.positions.button {
+            self.positions.button = (self.positions.button + count - 1) % count;
+        }
+        Ok(player)
+    }
+
+    fn street(&self) -> Street {
+        match self.state {
+            GameState::PreFlop => Street::PreFlop,
+            GameState::Flop => Street::Flop,
+            GameState::Turn => Street::Turn,
+            GameState::River => Street::River,
+            GameState::Showdown | GameState::Closed => Street::Showdown,
+        }
+    }
+
+    fn new_street(&mut self, street: Street, cards: u8) {
+        self.emit(GameEvent::StreetAdvanced { street });
+        let cards = self.deal_community(cards);
+        self.emit(GameEvent::CommunityDealt { street, cards });
+
+        for player in self.players.iter_mut() {
+            if player.is_active() {
+                player.last_action = PlayerAction::None;
+            }
+            player.bid = Chips::ZERO;
+        }
+        self.current_bid = Chips::ZERO;
+        self.last_raise = self.big_blind;
+        self.bets = 0;
+        // After the flop action starts left of the button
+        self.turn = (self.positions.button + 1) % self.players.len();
+        self.open_action();
+    }
+
+    // Awards every pot separately among the players eligible for it. Winners of a
+    // pot are ordered starting left of the button, so the first winner is the one
+    // that receives any odd chips.
+    fn showdown(&mut self) -> HandResult {
+        self.state = GameState::Showdown;
+        let count = self.players.len();
+        let contested = self.players.iter().filter(|player| player.in_hand()).count() > 1;
+        if contested {
+            self.emit(GameEvent::StreetAdvanced { street: Street::Showdown });
+        }
+        let mut hand_values: Vec<HandRank> = Vec::new();
+        let mut contributions: Vec<(Chips, bool)> = Vec::new();
+        for player in self.players.iter_mut() {
+            let in_hand = player.in_hand();
+            hand_values.push(if in_hand { player.hand.hand_value(&self.community) } else { HandRank::default() });
+            contributions.push((player.contributed, in_hand));
+        }
+
+        // The part of the biggest bet that nobody else matched goes back to the
+        // bettor untouched. The rake comes out of everything else, the main pot first.
+        let uncalled = uncalled_bet(&contributions);
+        if let Some((seat, amount)) = uncalled {
+            contributions[seat].0 -= amount;
+        }
+        let mut pots = build_pots(&contributions);
+        let saw_flop = self.community.cards[0].rank != Rank::None;
+        let rake = self.rake.take(pots.iter().map(|pot| pot.amount).sum(), saw_flop);
+        let mut to_rake = rake;
+        for pot in pots.iter_mut() {
+            let taken = to_rake.min(pot.amount);
+            pot.amount -= taken;
+            to_rake -= taken;
+        }
+        if let Some((seat, amount)) = uncalled {
+            pots.push(Pot { amount, eligible: vec![seat] });
+        }
+        pots.retain(|pot| !pot.amount.is_zero());
+        self.rake_collected += rake;
+        if !rake.is_zero() {
+            self.emit(GameEvent::RakeTaken { amount: rake });
+        }
+
+        let mut payouts: Vec<Payout> = Vec::new();
+        for pot in pots {
+            let ranks: Vec<HandRank> = pot.eligible.iter().map(|&idx| hand_values[idx]).collect();
+            let mut winners: Vec<usize> = winning_hands(&ranks).iter().map(|&idx| pot.eligible[idx]).collect();
+            winners.sort_by_key(|&seat| (seat + count - self.positions.button - 1) % count);
+            let shares = split_pot(pot.amount, winners.len());
+            for (&winner_idx, &share) in winners.iter().zip(shares.iter()) {
+                let winner = &mut self.players[winner_idx];
+                winner.bank += share;
+                payouts.push(Payout {
+                    seat: winner_idx,
+                    name: winner.name.clone(),
+                    amount: share,
+                    hand: if contested { Some(winner.hand.best_hand(&self.community)) } else { None },
+                });
+            }
+        }
+        self.pot = Chips::ZERO;
+        self.current_bid = Chips::ZERO;
+
+        for payout in payouts.iter() {
+            self.emit(GameEvent::PotAwarded { seat: payout.seat, amount: payout.amount, hand: payout.hand });
+        }
+        let busted: Vec<usize> = (0..self.players.len())
+            .filter(|&seat| self.players[seat].bank.is_zero() && self.players[seat].hand.cards[0].rank != Rank::None)
+            .collect();
+        for seat in busted {
+            self.emit(GameEvent::PlayerBusted { seat });
+        }
+
+        HandResult {
+            community: self.community,
+            payouts,
+            rake,
         }
-        self.current_bid = 0.0;
     }
 
-    pub fn deal_community(&mut self, count: u8) {
+    pub fn deal_community(&mut self, count: u8) -> Vec<Card> {
         // Find the first card that hasn't been dealt
         let mut index = 0;
         for (i, card) in self.community.cards.iter().enumerate() {
@@ -211,41 +690,236 @@ impl Game {
             }
         }
         // Deal the cards
+        let mut cards = Vec::new();
         for _ in 0..count {
             let card = self.deck.deal();
             self.community.cards[index] = card;
+            cards.push(card);
             index += 1;
         }
+        cards
+    }
 
-        // TODO: make argument for printing injected into this function
-        self.community.print(std::io::stdout());
+    // What the player in `seat` can see of the table
+    pub fn table_view(&self, seat: usize) -> TableView {
+        let player = &self.players[seat];
+        TableView {
+            seat,
+            name: player.name.clone(),
+            hand: player.hand,
+            community: self.community,
+            pot: self.pot,
+            current_bid: self.current_bid,
+            bid: player.bid,
+            bank: player.bank,
+            legal_actions: self.legal_actions(seat),
+        }
     }
 
-    pub fn loop_turns(&mut self) {
-        let player_last_actions = self.get_active_players().iter().map(|player| player.last_action).collect::<Vec<PlayerAction>>();
-        // If all players have checked, folded, called, or gone all in, then advance the state
-        if player_last_actions.iter().all(|&action| action == PlayerAction::Check || action == PlayerAction::Fold || action == PlayerAction::Call || action == PlayerAction::AllIn) {
-            self.advance_state();
+    // What the player in `seat` may do right now under the betting structure.
+    // Nothing when it is not their turn.
+    pub fn legal_actions(&self, seat: usize) -> LegalActions {
+        let player = &self.players[seat];
+        let to_call = self.current_bid.saturating_sub(player.bid);
+        // What is left to raise with after calling
+        let behind = player.bank.saturating_sub(to_call);
+        let limits = self.structure.raise_limits(self.street(), self.last_raise, self.pot, to_call, self.bets);
+        let mut legal = LegalActions {
+            actions: Vec::new(),
+            to_call,
+            min_raise: limits.map_or(Chips::ZERO, |limits| limits.min),
+            max_raise: limits.map_or(Chips::ZERO, |limits| limits.max.min(behind)),
+        };
+        if self.to_act() != Some(seat) {
+            return legal;
+        }
+        legal.actions.push(PlayerAction::Fold);
+        if to_call.is_zero() {
+            legal.actions.push(PlayerAction::Check);
+        } else if to_call < player.bank {
+            legal.actions.push(PlayerAction::Call);
+        }
+        // Raising is closed to a player who already acted and has only faced an
+        // all in for less than a full raise since
+        let raising = limits.is_some() && !behind.is_zero() && !self.acted[seat];
+        if raising && legal.min_raise <= legal.max_raise {
+            legal.actions.push(PlayerAction::Raise);
+        }
+        // Going all in is allowed when it is no more than a legal raise, even if it
+        // is less than a full one, or when it is all the player has left to call
+        if behind.is_zero() || (raising && limits.is_some_and(|limits| behind <= limits.max)) {
+            legal.actions.push(PlayerAction::AllIn);
+        }
+        legal
+    }
+
+    // Applies the action of the player in `seat`, who has to be the player to
+    // act. A raise is by `amount` on top of the current bid, and raising by
+    // everything left behind puts the player all in. Returns the result once the action ends
+    // the hand.
+    pub fn apply_action(&mut self, seat: usize, action: PlayerAction, amount: Chips) -> Result<Option<HandResult>, ActionError> {
+        let to_act = self.to_act().ok_or(ActionError::NoHandInProgress)?;
+        if seat != to_act {
+            return Err(ActionError::OutOfTurn { seat, to_act });
+        }
+        let legal = self.legal_actions(seat);
+        if !legal.contains(&action) {
+            return Err(ActionError::IllegalAction(action));
+        }
+        if action == PlayerAction::Raise {
+            if amount > legal.max_raise {
+                return Err(ActionError::RaiseTooLarge { amount, max_raise: legal.max_raise });
+            }
+            if amount < legal.min_raise {
+                return Err(ActionError::RaiseTooSmall { amount, min_raise: legal.min_raise });
+            }
         }
 
-        let player = &mut self.players[self.turn];
-        if player.is_active() {
-            let (action, pot_contribution) = player.prompt_action(std::io::stdin().lock(), std::io::stdout(), Some(self.current_bid));
-            self.pot += pot_contribution;
-            if action == PlayerAction::Raise {
-                let raised_bid = player.bid - self.current_bid;
-                self.current_bid += raised_bid;
-            } else if action == PlayerAction::AllIn {
-                let raised_bid = player.bid - self.current_bid;
-                if raised_bid > self.current_bid {
-                    self.current_bid += raised_bid;
+        let previous_bid = self.current_bid;
+        let player = &mut self.players[seat];
+        let pot_contribution = match action {
+            PlayerAction::Check => {
+                player.check();
+                Chips::ZERO
+            },
+            PlayerAction::Call => player.call(self.current_bid).ok_or(ActionError::IllegalAction(action))?,
+            PlayerAction::Raise if legal.to_call + amount == player.bank => player.all_in(),
+            PlayerAction::Raise => player.raise(self.current_bid + amount).ok_or(ActionError::IllegalAction(action))?,
+            PlayerAction::AllIn => player.all_in(),
+            PlayerAction::Fold | PlayerAction::None => {
+                player.fold();
+                Chips::ZERO
+            },
+        };
+        // Raising by the whole bank went all in
+        let action = player.last_action;
+        self.pot += pot_contribution;
+        if player.bid > self.current_bid {
+            self.current_bid = player.bid;
+        }
+        // An all in for less than a full raise does not change the minimum raise
+        let raised_by = self.current_bid - previous_bid;
+        let full_raise = raised_by >= self.last_raise;
+        if full_raise {
+            self.last_raise = raised_by;
+        }
+        if !raised_by.is_zero() {
+            self.bets += 1;
+        }
+        self.needs_action[seat] = false;
+        if self.current_bid > previous_bid {
+            // Everyone else still betting has to answer the raise, and only a full
+            // raise lets those who already acted raise again
+            for (other, player) in self.players.iter().enumerate() {
+                if other != seat && player.is_active() {
+                    self.needs_action[other] = true;
+                    if full_raise {
+                        self.acted[other] = false;
+                    }
                 }
             }
         }
-        self.turn += 1;
-        if self.turn >= self.players.len() {
-            self.turn = 0;
+        self.acted[seat] = true;
+        self.emit(GameEvent::PlayerActed { seat, action, amount: pot_contribution });
+        self.turn = (seat + 1) % self.players.len();
+        Ok(self.settle())
+    }
+
+    // Applies a strategy's decision for the player in `seat`. A raise that is too
+    // small raises the minimum and one above the limit raises the most allowed. A
+    // raise that is not allowed at all puts a short stack all in when that is legal
+    // and calls or checks otherwise. An all in that is not allowed raises the most
+    // allowed, or calls or checks. Anything else against the rules is a fold.
+    fn apply_decision(&mut self, seat: usize, action: PlayerAction, amount: Chips) -> Option<HandResult> {
+        let applied = match self.apply_action(seat, action, amount) {
+            Err(ActionError::RaiseTooSmall { min_raise, .. }) => self.apply_action(seat, PlayerAction::Raise, min_raise),
+            Err(ActionError::RaiseTooLarge { max_raise, .. }) => self.apply_action(seat, PlayerAction::Raise, max_raise),
+            Err(ActionError::IllegalAction(PlayerAction::Raise)) => self.apply_action(seat, PlayerAction::AllIn, Chips::ZERO)
+                .or_else(|_| self.apply_action(seat, PlayerAction::Call, Chips::ZERO))
+                .or_else(|_| self.apply_action(seat, PlayerAction::Check, Chips::ZERO)),
+            Err(ActionError::IllegalAction(PlayerAction::AllIn)) => {
+                let max_raise = self.legal_actions(seat).max_raise;
+                self.apply_action(seat, PlayerAction::Raise, max_raise)
+                    .or_else(|_| self.apply_action(seat, PlayerAction::Call, Chips::ZERO))
+                    .or_else(|_| self.apply_action(seat, PlayerAction::Check, Chips::ZERO))
+            },
+            applied => applied,
+        };
+        let applied = applied.or_else(|_| self.apply_action(seat, PlayerAction::Fold, Chips::ZERO));
+        applied.expect("the player to act can always fold")
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pot {
+    pub amount: Chips,
+    // Indexes of the players that can win this pot
+    pub eligible: Vec<usize>,
+}
+
+// The seat still in the hand that put in more than anyone else, and by how
+// much, when nobody matched all of their bet
+fn uncalled_bet(contributions: &[(Chips, bool)]) -> Option<(usize, Chips)> {
+    let (seat, &(most, in_hand)) = contributions.iter().enumerate().max_by_key(|(_, (amount, _))| *amount)?;
+    let matched = contributions.iter().enumerate()
+        .filter(|&(other, _)| other != seat)
+        .map(|(_, (amount, _))| *amount)
+        .max()
+        .unwrap_or(Chips::ZERO);
+    if !in_hand || most <= matched {
+        return None;
+    }
+    Some((seat, most - matched))
+}
+
+// Builds the main pot and side pots from each player's total contribution to the
+// hand and whether they are still in it. A player is only eligible for the pots
+// they matched in full; chips from folded players stay in the pots they reached.
+pub fn build_pots(contributions: &[(Chips, bool)]) -> Vec<Pot> {
+    let mut levels: Vec<Chips> = contributions.iter().filter(|(_, in_hand)| *in_hand).map(|(amount, _)| *amount).collect();
+    levels.sort();
+    levels.dedup();
+
+    let mut pots: Vec<Pot> = Vec::new();
+    let mut previous = Chips::ZERO;
+    for (i, &level) in levels.iter().enumerate() {
+        let last = i == levels.len() - 1;
+        let mut amount = Chips::ZERO;
+        for (contributed, _) in contributions.iter() {
+            // Dead money above the highest level still belongs in the last pot
+            let capped = if last { *contributed } else { (*contributed).min(level) };
+            amount += capped.saturating_sub(previous);
+        }
+        let eligible: Vec<usize> = contributions.iter().enumerate()
+            .filter(|(_, (contributed, in_hand))| *in_hand && *contributed >= level)
+            .map(|(idx, _)| idx)
+            .collect();
+        if !amount.is_zero() {
+            pots.push(Pot { amount, eligible });
         }
-        return self.loop_turns();
+        previous = level;
+    }
+
+    pots
+}
+
+// Returns the indexes of every hand tied for the best rank
+pub fn winning_hands(ranks: &[HandRank]) -> Vec<usize> {
+    let best = match ranks.iter().max() {
+        Some(best) => *best,
+        None => return Vec::new(),
+    };
+    ranks.iter().enumerate().filter(|(_, rank)| **rank == best).map(|(idx, _)| idx).collect()
+}
+
+// Splits a pot evenly between `winners`. Odd chips that cannot be divided go to
+// the first winner, who is the first seat left of the button.
+pub fn split_pot(pot: Chips, winners: usize) -> Vec<Chips> {
+    if winners == 0 {
+        return Vec::new();
     }
+    let (share, remainder) = pot.split(winners as u64);
+
+    let mut shares = vec![share; winners];
+    shares[0] += remainder;
+    shares
 }
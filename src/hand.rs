@@ -1,10 +1,18 @@
-use std::collections::HashMap;
-use crate::deck::{Card, Suit, Rank, Deck, Community};
+use std::fmt;
+use std::str::FromStr;
+use crate::deck::{parse_cards, Card, CardParseError, Suit, Rank, Deck, Community};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Hand {
     pub cards: [Card; 2],
-    pub value: (ScoringHands, u8),
+    pub value: HandRank,
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Hand {
@@ -14,7 +22,7 @@ impl Hand {
                 suit: Suit::None,
                 rank: Rank::None,
             }; 2],
-            value: (ScoringHands::None, 0),
+            value: HandRank::default(),
         }
     }
 
@@ -23,7 +31,7 @@ impl Hand {
             suit: Suit::None,
             rank: Rank::None,
         }; 2];
-        self.value = (ScoringHands::None, 0);
+        self.value = HandRank::default();
     }
 
     pub fn fill(&mut self, deck: &mut Deck) {
@@ -31,140 +39,311 @@ impl Hand {
         self.cards[1] = deck.deal();
     }
 
-    pub fn hand_value(&mut self, community: &Community) -> (ScoringHands, u8) {
+    pub fn hand_value(&mut self, community: &Community) -> HandRank {
         self.value = self.determine_value(community);
         self.value
     }
 
-    pub fn determine_value(&mut self, community: &Community) -> (ScoringHands, u8) {
-        let mut cards_by_rank: HashMap<Rank, Vec<Card>> = HashMap::new();
-        let mut cards_by_suit: HashMap<Suit, Vec<Card>> = HashMap::new();
+    pub fn determine_value(&self, community: &Community) -> HandRank {
+        self.best_hand(community).rank
+    }
 
-        let all_cards = [self.cards[0], self.cards[1], community.cards[0], community.cards[1], community.cards[2], community.cards[3], community.cards[4]].to_vec();
+    pub fn best_hand(&self, community: &Community) -> BestHand {
+        let all_cards = [self.cards[0], self.cards[1], community.cards[0], community.cards[1], community.cards[2], community.cards[3], community.cards[4]];
 
-        for card in all_cards.iter() {
-            let rank = card.rank;
-            let suit = card.suit;
+        best_hand(&all_cards)
+    }
+}
 
-            if cards_by_rank.contains_key(&rank) {
-                cards_by_rank.get_mut(&rank).unwrap().push(*card);
-            } else {
-                cards_by_rank.insert(rank, vec![*card]);
-            }
+// Reads two hole cards, like "AsKd" or "As Kd"
+impl FromStr for Hand {
+    type Err = CardParseError;
 
-            if cards_by_suit.contains_key(&suit) {
-                cards_by_suit.get_mut(&suit).unwrap().push(*card);
-            } else {
-                cards_by_suit.insert(suit, vec![*card]);
-            }
+    fn from_str(s: &str) -> Result<Hand, CardParseError> {
+        match parse_cards(s)?[..] {
+            [first, second] => Ok(Hand { cards: [first, second], value: HandRank::default() }),
+            ref cards => Err(CardParseError::WrongCount { expected: 2, found: cards.len() }),
         }
+    }
+}
 
-        // Check for Royal Flush, Straight Flush, and Straight
-        for (_, cards) in cards_by_suit.iter() {
-            if cards.len() >= 5 {
-                let mut cards_ranked = cards.clone();
-                cards_ranked.sort_by(|a, b| b.rank.cmp(&a.rank));
-                cards_ranked.dedup_by(|a, b| a.rank == b.rank);
-                if cards_ranked.len() < 5 {
-                    continue;
-                }
-
-                // Check if cards_ranked contains a card with rank ten, jack, queen, king, and ace in it
-                let royal_flush_ranks = [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace];
-                let card_ranks = cards_ranked.iter().map(|card| card.rank).collect::<Vec<Rank>>();
-                if royal_flush_ranks.iter().all(|rank| card_ranks.contains(&rank)) {
-                    return (ScoringHands::RoyalFlush, cards_ranked[0].rank as u8);
-                }
-
-                // Straight Flush
-                for i in 0..cards_ranked.len()-5 {
-                    if cards_ranked[i].rank as u8 + 1 == cards_ranked[i + 1].rank as u8 && cards_ranked[i + 1].rank as u8 + 1 == cards_ranked[i + 2].rank as u8 && cards_ranked[i + 2].rank as u8 + 1 == cards_ranked[i + 3].rank as u8 && cards_ranked[i + 3].rank as u8 + 1 == cards_ranked[i + 4].rank as u8 {
-                        return (ScoringHands::StraightFlush, cards_ranked[i].rank as u8);
-                    }
-                }
-            }
-        }
+// Category first, then the ranks that break ties within the category in the
+// order they are compared. Unused tiebreak slots are filled with `Rank::None`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct HandRank {
+    pub category: ScoringHands,
+    pub ranks: [Rank; 5],
+}
 
-        // Four of a Kind
-        for (rank, cards) in cards_by_rank.iter() {
-            if cards.len() == 4 {
-                return (ScoringHands::FourOfAKind, *rank as u8);
-            }
+impl Default for HandRank {
+    fn default() -> Self {
+        HandRank {
+            category: ScoringHands::None,
+            ranks: [Rank::None; 5],
         }
+    }
+}
 
-        // Full House
-        let mut pair: u8 = 0;
-        let mut three_of_a_kind: u8 = 0;
-        for (rank, cards) in cards_by_rank.iter() {
-            if cards.len() == 3 && *rank as u8 > three_of_a_kind{
-                three_of_a_kind = *rank as u8;
-            }
-            if cards.len() == 2 && *rank as u8 > pair {
-                pair = *rank as u8;
-            }
-
-            if three_of_a_kind > 0 && pair > 0 {
-                return (ScoringHands::FullHouse, three_of_a_kind);
-            }
+impl HandRank {
+    pub fn new(category: ScoringHands, tiebreaks: &[Rank]) -> HandRank {
+        let mut ranks = [Rank::None; 5];
+        for (slot, rank) in ranks.iter_mut().zip(tiebreaks.iter()) {
+            *slot = *rank;
         }
+        HandRank { category, ranks }
+    }
+}
 
-        // Flush
-        for (_, cards) in cards_by_suit.iter() {
-            if cards.len() >= 5 {
-                let mut cards_ranked = cards.clone();
-                cards_ranked.sort_by(|a, b| b.rank.cmp(&a.rank));
-                return (ScoringHands::Flush, cards_ranked[0].rank as u8);
-            }
+impl fmt::Display for HandRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [first, second, third, ..] = self.ranks;
+        match self.category {
+            ScoringHands::None => write!(f, "No Hand"),
+            ScoringHands::HighCard => {
+                write!(f, "High Card, {}", rank_name(first))?;
+                write_kicker(f, second)
+            },
+            ScoringHands::OnePair => {
+                write!(f, "One Pair, {}", rank_plural(first))?;
+                write_kicker(f, second)
+            },
+            ScoringHands::TwoPair => {
+                write!(f, "Two Pair, {} and {}", rank_plural(first), rank_plural(second))?;
+                write_kicker(f, third)
+            },
+            ScoringHands::ThreeOfAKind => {
+                write!(f, "Three of a Kind, {}", rank_plural(first))?;
+                write_kicker(f, second)
+            },
+            ScoringHands::Straight => write!(f, "Straight, {} high", rank_name(first)),
+            ScoringHands::Flush => write!(f, "Flush, {} high", rank_name(first)),
+            ScoringHands::FullHouse => write!(f, "Full House, {} full of {}", rank_plural(first), rank_plural(second)),
+            ScoringHands::FourOfAKind => {
+                write!(f, "Four of a Kind, {}", rank_plural(first))?;
+                write_kicker(f, second)
+            },
+            ScoringHands::StraightFlush => write!(f, "Straight Flush, {} high", rank_name(first)),
+            ScoringHands::RoyalFlush => write!(f, "Royal Flush"),
         }
+    }
+}
 
-        // Straight
-        let mut cards_ranked = all_cards.clone();
-        cards_ranked.sort_by(|a, b| b.rank.cmp(&a.rank));
-        cards_ranked.dedup_by(|a, b| a.rank == b.rank);
-        if cards_ranked.len() >= 5 {
-            for i in 0..cards_ranked.len()-5 {
-                if cards_ranked[i].rank as u8 + 1 == cards_ranked[i + 1].rank as u8 && cards_ranked[i + 1].rank as u8 + 1 == cards_ranked[i + 2].rank as u8 && cards_ranked[i + 2].rank as u8 + 1 == cards_ranked[i + 3].rank as u8 && cards_ranked[i + 3].rank as u8 + 1 == cards_ranked[i + 4].rank as u8 {
-                    return (ScoringHands::Straight, cards_ranked[i].rank as u8);
-                }
-            }
+fn write_kicker(f: &mut fmt::Formatter, kicker: Rank) -> fmt::Result {
+    if kicker == Rank::None {
+        return Ok(());
+    }
+    write!(f, ", {} kicker", rank_name(kicker))
+}
+
+fn rank_name(rank: Rank) -> &'static str {
+    match rank {
+        Rank::None => "None",
+        Rank::Two => "Two",
+        Rank::Three => "Three",
+        Rank::Four => "Four",
+        Rank::Five => "Five",
+        Rank::Six => "Six",
+        Rank::Seven => "Seven",
+        Rank::Eight => "Eight",
+        Rank::Nine => "Nine",
+        Rank::Ten => "Ten",
+        Rank::Jack => "Jack",
+        Rank::Queen => "Queen",
+        Rank::King => "King",
+        Rank::Ace => "Ace",
+    }
+}
+
+fn rank_plural(rank: Rank) -> &'static str {
+    match rank {
+        Rank::None => "None",
+        Rank::Two => "Twos",
+        Rank::Three => "Threes",
+        Rank::Four => "Fours",
+        Rank::Five => "Fives",
+        Rank::Six => "Sixes",
+        Rank::Seven => "Sevens",
+        Rank::Eight => "Eights",
+        Rank::Nine => "Nines",
+        Rank::Ten => "Tens",
+        Rank::Jack => "Jacks",
+        Rank::Queen => "Queens",
+        Rank::King => "Kings",
+        Rank::Ace => "Aces",
+    }
+}
+
+// The five cards that make up a hand together with their rank. When fewer than
+// five cards are available the remaining slots are left undealt.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BestHand {
+    pub rank: HandRank,
+    pub cards: [Card; 5],
+}
+
+impl BestHand {
+    fn new(category: ScoringHands, tiebreaks: &[Rank], cards: &[Card]) -> BestHand {
+        let mut best = [Card {
+            suit: Suit::None,
+            rank: Rank::None,
+        }; 5];
+        for (slot, card) in best.iter_mut().zip(cards.iter()) {
+            *slot = *card;
+        }
+        BestHand {
+            rank: HandRank::new(category, tiebreaks),
+            cards: best,
         }
+    }
+}
+
+impl fmt::Display for BestHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.rank.fmt(f)
+    }
+}
+
+// Ranks the best five card hand that can be made from `cards`. Undealt cards
+// (`Rank::None`) are ignored, so this works for any number of cards.
+pub fn evaluate(cards: &[Card]) -> HandRank {
+    best_hand(cards).rank
+}
+
+// Same as `evaluate`, but also returns the five cards that make up the hand.
+pub fn best_hand(cards: &[Card]) -> BestHand {
+    let mut sorted: Vec<Card> = cards.iter().filter(|card| card.rank != Rank::None).copied().collect();
+    sorted.sort_by_key(|card| std::cmp::Reverse(card.rank));
 
-        // Three of a Kind
-        let mut three_of_a_kind: u8 = 0;
-        for (rank, cards) in cards_by_rank.iter() {
-            if cards.len() == 3 && *rank as u8 > three_of_a_kind{
-                three_of_a_kind = *rank as u8;
+    // Straight Flush, Royal Flush, and Flush
+    let mut flush: Option<Vec<Card>> = None;
+    for suit in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades].iter() {
+        let suited: Vec<Card> = sorted.iter().filter(|card| card.suit == *suit).copied().collect();
+        if suited.len() < 5 {
+            continue;
+        }
+        if let Some(straight) = find_straight(&suited) {
+            let high = straight[0].rank;
+            if high == Rank::Ace {
+                return BestHand::new(ScoringHands::RoyalFlush, &[high], &straight);
             }
+            return BestHand::new(ScoringHands::StraightFlush, &[high], &straight);
         }
-        if three_of_a_kind > 0 {
-            return (ScoringHands::ThreeOfAKind, three_of_a_kind);
+        flush = Some(suited);
+    }
+
+    // Group cards by rank, largest groups first and higher ranks first within a size
+    let mut groups: Vec<Vec<Card>> = Vec::new();
+    for card in sorted.iter() {
+        match groups.iter_mut().find(|group| group[0].rank == card.rank) {
+            Some(group) => group.push(*card),
+            None => groups.push(vec![*card]),
         }
+    }
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then(b[0].rank.cmp(&a[0].rank)));
 
-        // One Pair
-        let mut pair_rank: u8 = 0;
-        let mut pair_count: u8 = 0;
-        for (rank, cards) in cards_by_rank.iter() {
-            if cards.len() == 2 {
-                pair_count += 1;
-                if *rank as u8 > pair_rank {
-                    pair_rank = *rank as u8;
-                }
+    // Fills out `made` with the highest cards not already used
+    let with_kickers = |made: &[Card]| -> Vec<Card> {
+        let mut cards = made.to_vec();
+        for card in sorted.iter() {
+            if cards.len() == 5 {
+                break;
+            }
+            if !made.iter().any(|used| used.rank == card.rank) {
+                cards.push(*card);
             }
         }
-        if pair_count == 1 {
-            return (ScoringHands::OnePair, pair_rank);
-        } else if pair_count >= 2 {
-            // Two Pair
-            return (ScoringHands::TwoPair, pair_rank);
-        }
+        cards
+    };
+    let ranks_of = |cards: &[Card], skip: usize| -> Vec<Rank> {
+        cards.iter().skip(skip).map(|card| card.rank).collect()
+    };
+
+    let largest = groups.first().map(|group| group.len()).unwrap_or(0);
+    let second = groups.get(1).map(|group| group.len()).unwrap_or(0);
+
+    // Four of a Kind
+    if largest == 4 {
+        let cards = with_kickers(&groups[0]);
+        let mut ranks = vec![groups[0][0].rank];
+        ranks.extend(ranks_of(&cards, 4));
+        return BestHand::new(ScoringHands::FourOfAKind, &ranks, &cards);
+    }
 
-        // High Card
-        (ScoringHands::HighCard, cards_ranked[0].rank as u8)
+    // Full House
+    if largest == 3 && second >= 2 {
+        let mut cards = groups[0].clone();
+        cards.extend(groups[1].iter().take(2));
+        return BestHand::new(ScoringHands::FullHouse, &[groups[0][0].rank, groups[1][0].rank], &cards);
+    }
+
+    // Flush
+    if let Some(suited) = flush {
+        let cards = &suited[..5];
+        return BestHand::new(ScoringHands::Flush, &ranks_of(cards, 0), cards);
+    }
+
+    // Straight
+    if let Some(straight) = find_straight(&sorted) {
+        return BestHand::new(ScoringHands::Straight, &[straight[0].rank], &straight);
+    }
+
+    // Three of a Kind
+    if largest == 3 {
+        let cards = with_kickers(&groups[0]);
+        let mut ranks = vec![groups[0][0].rank];
+        ranks.extend(ranks_of(&cards, 3));
+        return BestHand::new(ScoringHands::ThreeOfAKind, &ranks, &cards);
+    }
+
+    // Two Pair
+    if largest == 2 && second == 2 {
+        let mut pairs = groups[0].clone();
+        pairs.extend(groups[1].iter());
+        let cards = with_kickers(&pairs);
+        let mut ranks = vec![groups[0][0].rank, groups[1][0].rank];
+        ranks.extend(ranks_of(&cards, 4));
+        return BestHand::new(ScoringHands::TwoPair, &ranks, &cards);
+    }
+
+    // One Pair
+    if largest == 2 {
+        let cards = with_kickers(&groups[0]);
+        let mut ranks = vec![groups[0][0].rank];
+        ranks.extend(ranks_of(&cards, 2));
+        return BestHand::new(ScoringHands::OnePair, &ranks, &cards);
+    }
+
+    // High Card
+    if sorted.is_empty() {
+        return BestHand::new(ScoringHands::None, &[], &[]);
+    }
+    let cards = with_kickers(&[]);
+    BestHand::new(ScoringHands::HighCard, &ranks_of(&cards, 0), &cards)
+}
+
+// Returns the five cards of the best straight in `cards`, which must be sorted
+// from highest to lowest rank. The wheel (A-2-3-4-5) counts as five high.
+fn find_straight(cards: &[Card]) -> Option<Vec<Card>> {
+    let mut distinct = cards.to_vec();
+    distinct.dedup_by(|a, b| a.rank == b.rank);
+    if distinct.first().map(|card| card.rank) == Some(Rank::Ace) {
+        // Ace plays low as well
+        distinct.push(distinct[0]);
+    }
+
+    for window in distinct.windows(5) {
+        let connected = window.windows(2).all(|pair| {
+            pair[0].rank as u8 == pair[1].rank as u8 + 1 || (pair[0].rank == Rank::Two && pair[1].rank == Rank::Ace)
+        });
+        if connected {
+            return Some(window.to_vec());
+        }
     }
 
+    None
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ScoringHands {
     None,
     HighCard,
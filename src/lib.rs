@@ -1,4 +1,15 @@
+pub mod betting;
+pub mod cash;
+pub mod chips;
+pub mod config;
+pub mod evaluator;
+pub mod event;
 pub mod game;
 pub mod deck;
+pub mod equity;
 pub mod hand;
-pub mod player;
\ No newline at end of file
+pub mod player;
+pub mod rake;
+pub mod render;
+pub mod strategy;
+pub mod tournament;
@@ -1,6 +1,286 @@
-use rust_deck::game::Game;
+use clap::{Args, Parser, Subcommand};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_deck::chips::Chips;
+use rust_deck::config::GameConfig;
+use rust_deck::deck::parse_cards;
+use rust_deck::equity::{exhaustive, monte_carlo};
+use rust_deck::event::GameEvent;
+use rust_deck::game::{Game, GameResult};
+use rust_deck::hand::{best_hand, Hand};
+use rust_deck::render::{self, Colors};
+use rust_deck::strategy::{CallingStation, Prompt, Strategy};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::exit;
+
+#[derive(Parser)]
+#[command(name = "rust_deck", about = "Texas hold'em in the terminal")]
+struct Cli {
+    // Plays a table of four humans when left out
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    #[command(about = "Play a table at the terminal, with humans taking turns at the prompt")]
+    Play(PlayArgs),
+    #[command(about = "Let bots play hands against each other and report the stacks")]
+    Simulate(SimulateArgs),
+    #[command(about = "Work out how often each hand wins on a board, e.g. `equity AsKd QhQc --board 2c7dTh`")]
+    Equity(EquityArgs),
+    #[command(about = "Print a hand history saved with --history")]
+    Replay(ReplayArgs),
+}
+
+#[derive(Args, Default)]
+struct TableArgs {
+    #[arg(long, help = "Table config file, .toml or .json")]
+    config: Option<PathBuf>,
+    #[arg(long, help = "Chips every player starts with")]
+    stack: Option<Chips>,
+    #[arg(long)]
+    small_blind: Option<Chips>,
+    #[arg(long)]
+    big_blind: Option<Chips>,
+    #[arg(long, help = "Deal the same cards as an earlier game")]
+    seed: Option<u64>,
+    #[arg(long, help = "Save every event to this file for `replay`")]
+    history: Option<PathBuf>,
+}
+
+#[derive(Args, Default)]
+struct PlayArgs {
+    #[command(flatten)]
+    table: TableArgs,
+    #[arg(long, help = "Humans at the table, 4 unless set here or in the config")]
+    humans: Option<u8>,
+    #[arg(long, help = "Bots at the table, seated after the humans")]
+    bots: Option<u8>,
+    #[arg(long, default_value_t = Colors::None, help = "Suit colors: none, two or four")]
+    colors: Colors,
+}
+
+#[derive(Args)]
+struct SimulateArgs {
+    #[command(flatten)]
+    table: TableArgs,
+    #[arg(long, default_value_t = 1000, help = "Most hands to play, fewer when one bot wins the table")]
+    hands: u64,
+    #[arg(long, help = "Bots at the table, 4 unless set here or in the config")]
+    players: Option<u8>,
+}
+
+#[derive(Args)]
+struct EquityArgs {
+    #[arg(required = true, num_args = 2.., help = "Hole cards of each hand, e.g. AsKd")]
+    hands: Vec<String>,
+    #[arg(long, default_value = "", help = "The flop, turn or river, e.g. 2c7dTh, left out preflop")]
+    board: String,
+    #[arg(long, default_value = "", help = "Cards known to be out of the deck, e.g. folded hands")]
+    dead: String,
+    #[arg(long, help = "Deal this many random runouts instead of every one")]
+    trials: Option<u64>,
+    #[arg(long, help = "Deal the same random runouts as an earlier run")]
+    seed: Option<u64>,
+}
+
+#[derive(Args)]
+struct ReplayArgs {
+    file: PathBuf,
+    #[arg(long, help = "Only print this hand")]
+    hand: Option<u64>,
+    #[arg(long, default_value_t = Colors::None, help = "Suit colors: none, two or four")]
+    colors: Colors,
+    #[arg(long, help = "Show every player's hole cards face up")]
+    show_cards: bool,
+}
 
 fn main() {
-    let mut game = Game::new(4);
-    game.start();
+    let cli = Cli::parse();
+    let result = match cli.command.unwrap_or_else(|| Command::Play(PlayArgs::default())) {
+        Command::Play(args) => play(args),
+        Command::Simulate(args) => simulate(args),
+        Command::Equity(args) => equity(args),
+        Command::Replay(args) => replay(args),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
+
+fn play(args: PlayArgs) -> Result<(), String> {
+    let mut config = args.table.config()?;
+    // The first `humans` players are played at the prompt, every one of them
+    // unless the table is set up here
+    let mut humans = None;
+    if args.humans.is_some() || args.bots.is_some() {
+        let count = args.humans.unwrap_or(0);
+        let bots = args.bots.unwrap_or(0);
+        let names = (0..count).map(|i| format!("Player {}", i)).chain((0..bots).map(|i| format!("Bot {}", i)));
+        config = config.players(names);
+        humans = Some(usize::from(count));
+    }
+    config.validate().map_err(|err| err.to_string())?;
+    let humans = humans.unwrap_or(config.players.len());
+    let mut seated = 0;
+    let mut game = Game::from_config_with(&config, |_| {
+        seated += 1;
+        let strategy: Box<dyn Strategy> = if seated <= humans { Box::new(Prompt::stdio().with_colors(args.colors)) } else { Box::new(CallingStation) };
+        strategy
+    });
+    println!("Seed: {}", game.seed());
+    args.table.record(&mut game)?;
+
+    let mut names: Vec<String> = Vec::new();
+    let colors = args.colors;
+    game.subscribe(move |event| print_event(&mut names, event, colors, false));
+
+    while let GameResult::HandFinished(_) = game.play_hand() {}
+    Ok(())
+}
+
+fn simulate(args: SimulateArgs) -> Result<(), String> {
+    let mut config = args.table.config()?;
+    if let Some(players) = args.players {
+        config = config.players((0..players).map(|i| format!("Bot {}", i)));
+    }
+    config.validate().map_err(|err| err.to_string())?;
+
+    let mut game = Game::from_config_with(&config, |_| Box::new(CallingStation));
+    println!("Seed: {}", game.seed());
+    args.table.record(&mut game)?;
+
+    let mut played = 0;
+    while played < args.hands {
+        if let GameResult::TableWon { name, .. } = game.play_hand() {
+            println!("{} won the table", name);
+            break;
+        }
+        played += 1;
+    }
+    println!("Played {} hands", played);
+    for player in game.players() {
+        println!("{}: {}", player.name, player.bank);
+    }
+    Ok(())
+}
+
+fn equity(args: EquityArgs) -> Result<(), String> {
+    let hands = args.hands.iter()
+        .map(|hand| hand.parse::<Hand>().map(|hand| hand.cards).map_err(|err| format!("{}: {}", hand, err)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let board = parse_cards(&args.board).map_err(|err| err.to_string())?;
+    let dead = parse_cards(&args.dead).map_err(|err| err.to_string())?;
+
+    // Every runout is dealt unless a number of random ones is asked for
+    let results = match args.trials {
+        Some(trials) => {
+            let seed = args.seed.unwrap_or_else(rand::random);
+            println!("Seed: {}", seed);
+            monte_carlo(&hands, &board, &dead, trials, &mut StdRng::seed_from_u64(seed))
+        },
+        None => exhaustive(&hands, &board, &dead),
+    }
+    .map_err(|err| err.to_string())?;
+
+    for ((name, hand), result) in args.hands.iter().zip(hands.iter()).zip(results.iter()) {
+        print!(
+            "{}: win {:.2}%, tie {:.2}%, lose {:.2}%, equity {:.2}%",
+            name, result.win_percent(), result.tie_percent(), result.loss_percent(), result.equity_percent()
+        );
+        if board.len() == 5 {
+            print!(" with {}", best_hand(&[hand.as_slice(), board.as_slice()].concat()));
+        }
+        println!();
+    }
+    Ok(())
+}
+
+fn replay(args: ReplayArgs) -> Result<(), String> {
+    let file = File::open(&args.file).map_err(|err| format!("Unable to open {}: {}", args.file.display(), err))?;
+    let mut names: Vec<String> = Vec::new();
+    let mut hand_number = 0;
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|err| err.to_string())?;
+        let event: GameEvent = serde_json::from_str(&line).map_err(|err| format!("Invalid history: {}", err))?;
+        if let GameEvent::HandStarted { hand_number: number, .. } = event {
+            hand_number = number;
+        }
+        if args.hand.is_none_or(|hand| hand == hand_number) {
+            print_event(&mut names, &event, args.colors, args.show_cards);
+        }
+    }
+    Ok(())
+}
+
+impl TableArgs {
+    // The config file, if any, with the flags given on top of it
+    fn config(&self) -> Result<GameConfig, String> {
+        let mut config = match &self.config {
+            Some(path) => GameConfig::load(path).map_err(|err| err.to_string())?,
+            None => GameConfig::default(),
+        };
+        if let Some(stack) = self.stack {
+            config = config.starting_stack(stack);
+        }
+        let small_blind = self.small_blind.unwrap_or(config.small_blind);
+        let big_blind = self.big_blind.unwrap_or(config.big_blind);
+        config = config.blinds(small_blind, big_blind);
+        if let Some(seed) = self.seed {
+            config = config.seed(seed);
+        }
+        Ok(config)
+    }
+
+    // Writes every event of the game to the history file, one JSON object per line
+    fn record(&self, game: &mut Game) -> Result<(), String> {
+        let path = match &self.history {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let file = File::create(path).map_err(|err| format!("Unable to create {}: {}", path.display(), err))?;
+        let mut writer = BufWriter::new(file);
+        game.subscribe(move |event| {
+            let written = serde_json::to_writer(&mut writer, event).map_err(|err| err.to_string())
+                .and_then(|_| writeln!(writer).and_then(|_| writer.flush()).map_err(|err| err.to_string()));
+            if let Err(err) = written {
+                eprintln!("Unable to write history: {}", err);
+            }
+        });
+        Ok(())
+    }
+}
+
+// Hole cards are printed face down unless `show_cards` is set, since everyone
+// at the terminal sees what is printed
+fn print_event(names: &mut Vec<String>, event: &GameEvent, colors: Colors, show_cards: bool) {
+    match event {
+        GameEvent::HandStarted { hand_number, button, players } => {
+            *names = players.iter().map(|(name, _)| name.clone()).collect();
+            println!("\n\n\nHand #{}", hand_number);
+            println!("{} has the button", names[*button]);
+        },
+        GameEvent::StreetAdvanced { street } => println!("\n\n\nStarting {:?}", street),
+        GameEvent::BlindPosted { seat, kind, amount } => println!("{} posts {:?} of {}", names[*seat], kind, amount),
+        GameEvent::HoleCardsDealt { seat, cards } if show_cards => println!("{} is dealt {}", names[*seat], render::cards(cards, colors)),
+        GameEvent::HoleCardsDealt { seat, .. } => println!("{} is dealt {}", names[*seat], render::hidden(2)),
+        GameEvent::CommunityDealt { cards, .. } => println!("Dealt: {}", render::cards(cards, colors)),
+        GameEvent::PlayerActed { seat, action, amount } => println!("{}: {:?} {}", names[*seat], action, amount),
+        GameEvent::RakeTaken { amount } => println!("Rake: {}", amount),
+        GameEvent::PotAwarded { seat, amount, hand } => {
+            println!("Winner: {} ({})", names[*seat], amount);
+            if let Some(hand) = hand {
+                println!("Hand: {}", hand);
+            }
+        },
+        GameEvent::PlayerBusted { seat } => println!("{} is out", names[*seat]),
+        GameEvent::TableWon { name, .. } => {
+            println!("\n\n\nTable Winner: {}", name);
+            println!("Thanks for playing!");
+        },
+    }
 }
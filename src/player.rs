@@ -1,72 +1,101 @@
+use crate::chips::Chips;
+use crate::deck::Rank;
 use crate::hand::Hand;
-use std::io::{stdout, stdin, BufRead, Write};
+use crate::strategy::{Prompt, Strategy};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
 pub struct Player {
     id: u8,
     pub name: String,
-    pub bank: f32,
+    pub bank: Chips,
     pub hand: Hand,
     pub last_action: PlayerAction,
-    pub bid: f32,
+    pub bid: Chips,
+    pub contributed: Chips,
+    // Keeps the seat without being dealt in
+    pub sitting_out: bool,
+    pub strategy: Box<dyn Strategy>,
 }
 
 impl Player {
-    pub fn new(id: u8, name: String, bank: Option<f32>) -> Player {
+    // Seats a human player answering prompts on stdin
+    pub fn new(id: u8, name: String, bank: Option<Chips>) -> Player {
+        Player::with_strategy(id, name, bank, Box::new(Prompt::stdio()))
+    }
+
+    pub fn with_strategy(id: u8, name: String, bank: Option<Chips>, strategy: Box<dyn Strategy>) -> Player {
         Player {
             id,
             name,
-            bank: bank.unwrap_or(100.0),
+            bank: bank.unwrap_or(Chips::from_dollars(100)),
             hand: Hand::new(),
             last_action: PlayerAction::None,
-            bid: 0.0,
+            bid: Chips::ZERO,
+            contributed: Chips::ZERO,
+            sitting_out: false,
+            strategy,
         }
     }
 
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
     pub fn reset(&mut self) {
         self.hand.reset();
         self.last_action = PlayerAction::None;
-        self.bid = 0.0;
+        self.bid = Chips::ZERO;
+        self.contributed = Chips::ZERO;
     }
 
-    pub fn blind(&mut self, blind: f32) -> f32 {
-        let blind = if blind > self.bank {
-            self.bank
-        } else {
-            blind
-        };
-        self.bank -= blind;
-        self.bid += blind;
+    // Moves chips from the bank into this street's bid and the hand's total
+    fn put_in(&mut self, amount: Chips) {
+        self.bank -= amount;
+        self.bid += amount;
+        self.contributed += amount;
+    }
+
+    pub fn blind(&mut self, blind: Chips) -> Chips {
+        let blind = blind.min(self.bank);
+        self.put_in(blind);
 
         blind
     }
 
-    pub fn raise(&mut self, total_bid: f32) -> f32 {
-        let bid_diff = total_bid - self.bid;
+    // Puts in dead money that does not count towards this street's bid
+    pub fn ante(&mut self, ante: Chips) -> Chips {
+        let ante = ante.min(self.bank);
+        self.bank -= ante;
+        self.contributed += ante;
+
+        ante
+    }
+
+    // Returns the chips put in, or None when the bank cannot cover the raise
+    pub fn raise(&mut self, total_bid: Chips) -> Option<Chips> {
+        let bid_diff = total_bid.saturating_sub(self.bid);
         if bid_diff > self.bank {
-            // If return 0, then player cannot raise
-            return 0.0;
+            return None;
         }
-        self.bank -= bid_diff;
-        self.bid += bid_diff;
+        self.put_in(bid_diff);
 
         self.last_action = PlayerAction::Raise;
 
-        total_bid
+        Some(bid_diff)
     }
 
-    pub fn call(&mut self, current_bid: f32) -> f32{
-        let bid_diff = current_bid - self.bid;
+    // Returns the chips put in, or None when the bank cannot cover the call
+    pub fn call(&mut self, current_bid: Chips) -> Option<Chips> {
+        let bid_diff = current_bid.saturating_sub(self.bid);
         if bid_diff > self.bank {
-            // If return 0, then player cannot call
-            return 0.0
+            return None;
         }
-        self.bank -= bid_diff;
-        self.bid = current_bid;
+        self.put_in(bid_diff);
 
         self.last_action = PlayerAction::Call;
 
-        bid_diff
+        Some(bid_diff)
     }
 
     pub fn check(&mut self) {
@@ -77,111 +106,31 @@ impl Player {
         self.last_action = PlayerAction::Fold;
     }
 
-    pub fn all_in(&mut self) -> f32 {
+    pub fn all_in(&mut self) -> Chips {
         let amount = self.bank;
-        self.bank = 0.0;
-        self.bid += amount;
+        self.put_in(amount);
         self.last_action = PlayerAction::AllIn;
 
         amount
     }
 
+    // Has chips and is not sitting out, so is dealt into the next hand
+    pub fn can_play(&self) -> bool {
+        !self.bank.is_zero() && !self.sitting_out
+    }
+
+    // Dealt in and still able to bet
     pub fn is_active(&self) -> bool {
-        self.bank > 0.0 && [PlayerAction::Fold, PlayerAction::AllIn].contains(&self.last_action) == false
+        self.in_hand() && !self.bank.is_zero() && self.last_action != PlayerAction::AllIn
     }
 
-    // Reader + Writer injection from
-    // https://stackoverflow.com/questions/28370126/how-can-i-test-stdin-and-stdout
-    pub fn prompt_action<R, W>(&mut self, mut reader: R, mut write: W, current_bid: Option<f32>) -> (PlayerAction, f32) 
-    where
-        R: BufRead,
-        W: Write,
-    {
-        if [PlayerAction::Fold, PlayerAction::AllIn].contains(&self.last_action) {
-            return (self.last_action, 0.0);
-        }
-        let _ = stdout().flush();
-        let mut action = String::new();
-        writeln!(&mut write, "Player {}'s turn", self.id).expect("Unable to write");
-        writeln!(&mut write, "Player {}'s bank: {}", self.id, self.bank).expect("Unable to write");
-        writeln!(&mut write, "Current bid: {}", current_bid.unwrap_or(0.0)).expect("Unable to write");
-        writeln!(&mut write, "Player {}'s hand: {:?}", self.id, self.hand).expect("Unable to write");
-        writeln!(&mut write, "Enter action: [fold|check|call|all-in|raise] [amount]").expect("Unable to write");
-        match reader.read_line(&mut action) {
-            Ok(_) => {
-                let action = action.trim().to_lowercase();
-                let action: Vec<&str> = action.split(" ").collect();
-                match action[0] {
-                    "fold" => {
-                        self.fold();
-                        (PlayerAction::Fold, 0.0)
-                    },
-                    "check" => {
-                        if current_bid.unwrap() > 0.0 {
-                            writeln!(&mut write, "Cannot check. Must at least call. Current bid {}", current_bid.unwrap_or(0.0)).expect("Unable to write");
-                            return self.prompt_action(reader, write, current_bid);
-                        } else {
-                            self.check();
-                            (PlayerAction::Check, 0.0)
-                        }
-                    },
-                    "call" => {
-                        let player_bid_diff = self.call(current_bid.unwrap_or(0.0));
-                        if player_bid_diff == 0.0 {
-                            writeln!(
-                                &mut write, 
-                                "Insufficient funds to call. Must all-in or fold.\nCurrent bid is {}.\nCurrent bank is {}", current_bid.unwrap_or(0.0), self.bank
-                            ).expect("Unable to write");
-                            return self.prompt_action(reader, write, current_bid);
-                        }
-                        (PlayerAction::Call, player_bid_diff)
-                    },
-                    "all-in" => {
-                        let player_remainder = self.all_in();
-                        (PlayerAction::AllIn, player_remainder)
-                    },
-                    "raise" => {
-                        match action.len() {
-                            2 => {
-                                let raise = action[1].parse::<f32>();
-                                match raise {
-                                    Ok(raise) => {
-                                        let raised_ammount = self.raise(current_bid.unwrap_or(0.0) + raise);
-                                        if raised_ammount == 0.0 {
-                                            writeln!(
-                                                &mut write, 
-                                                "Insufficient funds to raise.\nCurrent table bid is {}.\nCurrent bank is {}\nYour current bid is {}", current_bid.unwrap_or(0.0), self.bank, self.bid
-                                            ).expect("Unable to write");
-                                        }
-                                        (PlayerAction::Raise, raised_ammount)
-                                    },
-                                    Err(_) => {
-                                        writeln!(&mut write, "Invalid amount").expect("Unable to write");
-                                        self.prompt_action(reader, write, current_bid)
-                                    },
-                                }
-                            },
-                            _ => {
-                                writeln!(&mut write, "Invalid amount").expect("Unable to write");
-                                self.prompt_action(reader, write, current_bid)
-                            },
-                        }
-                    },
-                    _ => {
-                        writeln!(&mut write, "Invalid action").expect("Unable to write");
-                        self.prompt_action(reader, write, current_bid)
-                    },
-                }
-            },
-            Err(_) => {
-                writeln!(&mut write, "Invalid action").expect("Unable to write");
-                self.prompt_action(reader, write, current_bid)
-            },
-        }
+    // Still holding cards and contesting the pot, including when all in
+    pub fn in_hand(&self) -> bool {
+        self.hand.cards[0].rank != Rank::None && self.last_action != PlayerAction::Fold
     }
 }
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum PlayerAction {
     Fold,
     Check,
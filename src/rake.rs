@@ -0,0 +1,39 @@
+use crate::chips::Chips;
+use serde::{Deserialize, Serialize};
+
+// The house's cut of every pot
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rake {
+    // Whole percent of the pot, rounded down to the chip
+    pub percent: u32,
+    // The most taken from a single hand, no limit when left out
+    pub cap: Option<Chips>,
+    // Hands that end before the flop are not raked
+    pub no_flop_no_drop: bool,
+}
+
+impl Default for Rake {
+    // No rake at all
+    fn default() -> Self {
+        Rake {
+            percent: 0,
+            cap: None,
+            no_flop_no_drop: true,
+        }
+    }
+}
+
+impl Rake {
+    // How much to take from a hand with `pot` in the middle
+    pub fn take(&self, pot: Chips, saw_flop: bool) -> Chips {
+        if self.no_flop_no_drop && !saw_flop {
+            return Chips::ZERO;
+        }
+        let rake = Chips::new((pot.count() as u128 * self.percent.min(100) as u128 / 100) as u64);
+        match self.cap {
+            Some(cap) => rake.min(cap),
+            None => rake,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::deck::{Card, Rank, Suit};
+use std::fmt;
+use std::str::FromStr;
+
+// Drawn in place of a card that has not been dealt yet
+pub const UNDEALT: &str = "--";
+// Drawn in place of a card that is dealt but face down
+pub const CARD_BACK: &str = "▒▒";
+
+const RESET: &str = "\x1b[0m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const BLUE: &str = "\x1b[34m";
+
+// How suits are colored on a terminal
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Colors {
+    // Plain text, for terminals without color and for files
+    #[default]
+    None,
+    // Hearts and diamonds in red
+    TwoColor,
+    // Hearts red, diamonds blue, clubs green and spades left plain
+    FourColor,
+}
+
+impl Colors {
+    fn of(&self, suit: Suit) -> Option<&'static str> {
+        match (self, suit) {
+            (Colors::None, _) | (_, Suit::None) => None,
+            (_, Suit::Hearts) => Some(RED),
+            (Colors::TwoColor, Suit::Diamonds) => Some(RED),
+            (Colors::FourColor, Suit::Diamonds) => Some(BLUE),
+            (Colors::FourColor, Suit::Clubs) => Some(GREEN),
+            (_, Suit::Clubs) | (_, Suit::Spades) => None,
+        }
+    }
+}
+
+impl FromStr for Colors {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Colors, String> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Colors::None),
+            "two" => Ok(Colors::TwoColor),
+            "four" => Ok(Colors::FourColor),
+            _ => Err(format!("{} is not a color scheme, use none, two or four", s)),
+        }
+    }
+}
+
+impl fmt::Display for Colors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Colors::None => write!(f, "none"),
+            Colors::TwoColor => write!(f, "two"),
+            Colors::FourColor => write!(f, "four"),
+        }
+    }
+}
+
+pub fn suit_symbol(suit: Suit) -> char {
+    match suit {
+        Suit::None => '-',
+        Suit::Clubs => '♣',
+        Suit::Diamonds => '♦',
+        Suit::Hearts => '♥',
+        Suit::Spades => '♠',
+    }
+}
+
+// A card like "A♠". Undealt cards are drawn as `UNDEALT`.
+pub fn card(card: &Card, colors: Colors) -> String {
+    if card.rank == Rank::None {
+        return String::from(UNDEALT);
+    }
+    let text = format!("{}{}", card.rank, suit_symbol(card.suit));
+    match colors.of(card.suit) {
+        Some(color) => format!("{}{}{}", color, text, RESET),
+        None => text,
+    }
+}
+
+// Cards separated by spaces, like "A♠ K♥ -- -- --"
+pub fn cards(cards: &[Card], colors: Colors) -> String {
+    cards.iter().map(|c| card(c, colors)).collect::<Vec<String>>().join(" ")
+}
+
+// `count` face down cards, like another player's hole cards
+pub fn hidden(count: usize) -> String {
+    vec![CARD_BACK; count].join(" ")
+}
@@ -0,0 +1,219 @@
+use crate::chips::Chips;
+use crate::deck::Community;
+use crate::hand::Hand;
+use crate::player::PlayerAction;
+use crate::render::{self, Colors};
+use std::fmt;
+use std::io::{self, stdin, stdout, BufRead, Read, Stdout, Write};
+
+// Everything a player is allowed to know when it is their turn to act
+#[derive(Debug, Clone)]
+pub struct TableView {
+    pub seat: usize,
+    pub name: String,
+    pub hand: Hand,
+    pub community: Community,
+    pub pot: Chips,
+    pub current_bid: Chips,
+    // What this player has already bid on this street
+    pub bid: Chips,
+    pub bank: Chips,
+    pub legal_actions: LegalActions,
+}
+
+impl TableView {
+    // Chips this player needs to put in to call the current bid
+    pub fn to_call(&self) -> Chips {
+        self.current_bid.saturating_sub(self.bid)
+    }
+}
+
+// What a player may do on their turn. Raise limits are on top of the current
+// bid, the same as the amount a strategy raises by.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LegalActions {
+    pub actions: Vec<PlayerAction>,
+    pub to_call: Chips,
+    // The smallest full raise, the size of the last raise or the big blind
+    pub min_raise: Chips,
+    // Raising by this much puts the player all in
+    pub max_raise: Chips,
+}
+
+impl LegalActions {
+    pub fn contains(&self, action: &PlayerAction) -> bool {
+        self.actions.contains(action)
+    }
+}
+
+// Decides what a seated player does on their turn. The returned amount is only
+// used for `PlayerAction::Raise`, where it is how much to raise on top of the
+// current bid.
+pub trait Strategy {
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips);
+}
+
+impl fmt::Debug for dyn Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Strategy")
+    }
+}
+
+// A human player answering prompts on a reader and writer, usually stdin and stdout.
+// Reader + Writer injection from
+// https://stackoverflow.com/questions/28370126/how-can-i-test-stdin-and-stdout
+pub struct Prompt<R, W> {
+    reader: R,
+    writer: W,
+    colors: Colors,
+}
+
+impl Prompt<SharedStdin, Stdout> {
+    pub fn stdio() -> Self {
+        Prompt::new(SharedStdin::default(), stdout())
+    }
+}
+
+// Reads stdin one line at a time without holding the lock between reads, so
+// every human seated at the table can prompt from the same terminal.
+#[derive(Debug, Default)]
+pub struct SharedStdin {
+    line: Vec<u8>,
+    consumed: usize,
+}
+
+impl Read for SharedStdin {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.consume(count);
+        Ok(count)
+    }
+}
+
+impl BufRead for SharedStdin {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.consumed >= self.line.len() {
+            self.line.clear();
+            self.consumed = 0;
+            stdin().lock().read_until(b'\n', &mut self.line)?;
+        }
+        Ok(&self.line[self.consumed..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.consumed += amount;
+    }
+}
+
+impl<R, W> Prompt<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    pub fn new(reader: R, writer: W) -> Self {
+        Prompt { reader, writer, colors: Colors::None }
+    }
+
+    // Colors the suits of the cards shown at the prompt
+    pub fn with_colors(mut self, colors: Colors) -> Self {
+        self.colors = colors;
+        self
+    }
+}
+
+impl<R, W> Strategy for Prompt<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips) {
+        let write = &mut self.writer;
+        writeln!(write, "{}'s turn", view.name).expect("Unable to write");
+        writeln!(write, "{}'s bank: {}", view.name, view.bank).expect("Unable to write");
+        writeln!(write, "Current bid: {}", view.current_bid).expect("Unable to write");
+        writeln!(write, "Community: {}", render::cards(&view.community.cards, self.colors)).expect("Unable to write");
+        writeln!(write, "{}'s hand: {}", view.name, render::cards(&view.hand.cards, self.colors)).expect("Unable to write");
+        loop {
+            writeln!(write, "Enter action: [fold|check|call|all-in|raise] [amount]").expect("Unable to write");
+            write.flush().expect("Unable to write");
+
+            let mut action = String::new();
+            match self.reader.read_line(&mut action) {
+                // Nobody is left to answer, so give up the hand
+                Ok(0) => return (PlayerAction::Fold, Chips::ZERO),
+                Ok(_) => {},
+                Err(_) => {
+                    writeln!(write, "Invalid action").expect("Unable to write");
+                    continue;
+                },
+            }
+            let action = action.trim().to_lowercase();
+            let action: Vec<&str> = action.split_whitespace().collect();
+            let decision = match action.first().copied() {
+                Some("fold") => (PlayerAction::Fold, Chips::ZERO),
+                Some("check") => (PlayerAction::Check, Chips::ZERO),
+                // Calling nothing is the same as checking
+                Some("call") if view.to_call().is_zero() => (PlayerAction::Check, Chips::ZERO),
+                Some("call") => (PlayerAction::Call, Chips::ZERO),
+                Some("all-in") => (PlayerAction::AllIn, Chips::ZERO),
+                Some("raise") => match action.get(1).map(|amount| amount.parse::<Chips>()) {
+                    Some(Ok(raise)) if !raise.is_zero() => (PlayerAction::Raise, raise),
+                    _ => {
+                        writeln!(write, "Invalid amount").expect("Unable to write");
+                        continue;
+                    },
+                },
+                _ => {
+                    writeln!(write, "Invalid action").expect("Unable to write");
+                    continue;
+                },
+            };
+
+            match decision.0 {
+                PlayerAction::Check if !view.legal_actions.contains(&PlayerAction::Check) => {
+                    writeln!(write, "Cannot check. Must at least call. Current bid {}", view.current_bid).expect("Unable to write");
+                },
+                PlayerAction::Call if !view.legal_actions.contains(&PlayerAction::Call) => {
+                    writeln!(
+                        write,
+                        "Insufficient funds to call. Must all-in or fold.\nCurrent bid is {}.\nCurrent bank is {}", view.current_bid, view.bank
+                    ).expect("Unable to write");
+                },
+                PlayerAction::Raise if !view.legal_actions.contains(&PlayerAction::Raise) || decision.1 > view.legal_actions.max_raise => {
+                    writeln!(
+                        write,
+                        "Insufficient funds to raise.\nCurrent table bid is {}.\nCurrent bank is {}\nYour current bid is {}", view.current_bid, view.bank, view.bid
+                    ).expect("Unable to write");
+                },
+                PlayerAction::AllIn if !view.legal_actions.contains(&PlayerAction::AllIn) => {
+                    if view.legal_actions.contains(&PlayerAction::Raise) {
+                        writeln!(write, "Cannot go all-in. Can raise by at most {}", view.legal_actions.max_raise).expect("Unable to write");
+                    } else {
+                        writeln!(write, "Cannot go all-in. Must call or fold").expect("Unable to write");
+                    }
+                },
+                PlayerAction::Raise if decision.1 < view.legal_actions.min_raise => {
+                    writeln!(write, "Raise too small. Must raise by at least {}", view.legal_actions.min_raise).expect("Unable to write");
+                },
+                _ => return decision,
+            }
+        }
+    }
+}
+
+// A bot that never folds or raises, it checks when it can and calls otherwise
+#[derive(Debug, Default)]
+pub struct CallingStation;
+
+impl Strategy for CallingStation {
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips) {
+        for action in [PlayerAction::Check, PlayerAction::Call, PlayerAction::AllIn] {
+            if view.legal_actions.contains(&action) {
+                return (action, Chips::ZERO);
+            }
+        }
+        (PlayerAction::Fold, Chips::ZERO)
+    }
+}
@@ -0,0 +1,300 @@
+use crate::betting::Ante;
+use crate::chips::Chips;
+use crate::game::Game;
+use crate::player::Player;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlindLevel {
+    pub small_blind: Chips,
+    pub big_blind: Chips,
+    pub ante: Ante,
+}
+
+// How long each blind level lasts
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LevelDuration {
+    Hands(u64),
+    Time(Duration),
+}
+
+// Blind levels played in order. The last level lasts until the tournament ends.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlindSchedule {
+    pub levels: Vec<BlindLevel>,
+    pub duration: LevelDuration,
+}
+
+impl BlindSchedule {
+    // The index of the level in play after `hands` hands and `elapsed` time
+    pub fn level_at(&self, hands: u64, elapsed: Duration) -> usize {
+        let level = match self.duration {
+            LevelDuration::Hands(0) => 0,
+            LevelDuration::Hands(length) => hands / length,
+            LevelDuration::Time(length) if length.is_zero() => 0,
+            LevelDuration::Time(length) => (elapsed.as_nanos() / length.as_nanos()) as u64,
+        };
+        (level as usize).min(self.levels.len().saturating_sub(1))
+    }
+}
+
+// How the prize pool is split, as the percentage paid to each place starting
+// with first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutTable {
+    percentages: Vec<u32>,
+}
+
+impl PayoutTable {
+    // None unless the percentages add up to 100
+    pub fn new(percentages: &[u32]) -> Option<PayoutTable> {
+        if percentages.iter().sum::<u32>() != 100 {
+            return None;
+        }
+        Some(PayoutTable { percentages: percentages.to_vec() })
+    }
+
+    // The prize for each paid place. Chips lost rounding down go to first place.
+    pub fn prizes(&self, pool: Chips) -> Vec<Chips> {
+        let mut prizes: Vec<Chips> = self.percentages.iter()
+            .map(|&percentage| Chips::new((pool.count() as u128 * percentage as u128 / 100) as u64))
+            .collect();
+        let paid: Chips = prizes.iter().copied().sum();
+        if let Some(first) = prizes.first_mut() {
+            *first += pool - paid;
+        }
+        prizes
+    }
+}
+
+// Players are told apart by their id, since they change seats and tables
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standing {
+    pub place: usize,
+    pub id: u8,
+    pub name: String,
+    pub prize: Chips,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TournamentStatus {
+    // Ids of the players knocked out in the round, best finish first
+    Running { eliminated: Vec<u8> },
+    Finished(Vec<Standing>),
+}
+
+// Runs one or more `Game` tables as a freezeout: the blinds go up on a schedule,
+// players who lose their chips are out, tables are balanced and broken as the
+// field shrinks, and the prize pool is paid by finishing place.
+pub struct Tournament {
+    tables: Vec<Game>,
+    seats_per_table: usize,
+    schedule: BlindSchedule,
+    payouts: PayoutTable,
+    buy_in: Chips,
+    entrants: usize,
+    level: usize,
+    hands_played: u64,
+    started: Instant,
+    // Players in the order they went out
+    out: Vec<Player>,
+    standings: Option<Vec<Standing>>,
+}
+
+impl Tournament {
+    // A single table tournament. Everyone at `game` has paid `buy_in` into the
+    // prize pool.
+    pub fn new(game: Game, schedule: BlindSchedule, buy_in: Chips, payouts: PayoutTable) -> Tournament {
+        let seats_per_table = game.players().len();
+        Tournament::with_tables(vec![game], seats_per_table, schedule, buy_in, payouts)
+    }
+
+    // Draws `players` into as few tables of at most `seats_per_table` as they
+    // fit, as evenly as possible. `seed` drives the seat draw and every table.
+    pub fn multi_table(
+        mut players: Vec<Player>,
+        seats_per_table: usize,
+        seed: u64,
+        schedule: BlindSchedule,
+        buy_in: Chips,
+        payouts: PayoutTable,
+    ) -> Tournament {
+        let seats_per_table = seats_per_table.max(2);
+        players.shuffle(&mut StdRng::seed_from_u64(seed));
+        let count = players.len().div_ceil(seats_per_table).max(1);
+        let mut seated: Vec<Vec<Player>> = (0..count).map(|_| Vec::new()).collect();
+        for (i, player) in players.into_iter().enumerate() {
+            seated[i % count].push(player);
+        }
+        let tables = seated.into_iter().enumerate()
+            .map(|(i, players)| Game::with_seed(players, seed.wrapping_add(i as u64)))
+            .collect();
+        Tournament::with_tables(tables, seats_per_table, schedule, buy_in, payouts)
+    }
+
+    fn with_tables(tables: Vec<Game>, seats_per_table: usize, schedule: BlindSchedule, buy_in: Chips, payouts: PayoutTable) -> Tournament {
+        let entrants = tables.iter().map(|table| table.players().len()).sum();
+        let mut tournament = Tournament {
+            tables,
+            seats_per_table,
+            schedule,
+            payouts,
+            buy_in,
+            entrants,
+            level: 0,
+            hands_played: 0,
+            started: Instant::now(),
+            out: Vec::new(),
+            standings: None,
+        };
+        tournament.apply_level(0);
+        tournament
+    }
+
+    pub fn tables(&self) -> &[Game] {
+        &self.tables
+    }
+
+    // Gives access to a table, e.g. to subscribe to its events
+    pub fn table_mut(&mut self, table: usize) -> &mut Game {
+        &mut self.tables[table]
+    }
+
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    // Rounds played, where every table plays one hand a round
+    pub fn hands_played(&self) -> u64 {
+        self.hands_played
+    }
+
+    pub fn prize_pool(&self) -> Chips {
+        self.buy_in * self.entrants as u64
+    }
+
+    // Ids of the players in the order they went out
+    pub fn eliminated(&self) -> Vec<u8> {
+        self.out.iter().map(|player| player.id()).collect()
+    }
+
+    // Plays until one player has all the chips
+    pub fn play(&mut self) -> Vec<Standing> {
+        loop {
+            if let TournamentStatus::Finished(standings) = self.play_hand() {
+                return standings;
+            }
+        }
+    }
+
+    // Plays a hand at every table
+    pub fn play_hand(&mut self) -> TournamentStatus {
+        let elapsed = self.started.elapsed();
+        self.play_hand_at(elapsed)
+    }
+
+    // Same as `play_hand`, with the blind clock at `elapsed` since the start
+    pub fn play_hand_at(&mut self, elapsed: Duration) -> TournamentStatus {
+        if let Some(standings) = &self.standings {
+            return TournamentStatus::Finished(standings.clone());
+        }
+        let level = self.schedule.level_at(self.hands_played, elapsed);
+        if level != self.level {
+            self.apply_level(level);
+        }
+
+        let mut busted: Vec<(Chips, Player)> = Vec::new();
+        for table in self.tables.iter_mut() {
+            let banks: Vec<Chips> = table.players().iter().map(|player| player.bank).collect();
+            if banks.iter().filter(|bank| !bank.is_zero()).count() > 1 {
+                table.play_hand();
+            }
+            for seat in (0..banks.len()).rev() {
+                if table.players()[seat].bank.is_zero() {
+                    let player = table.remove_player(seat).expect("no hand is in progress between hands");
+                    busted.push((banks[seat], player));
+                }
+            }
+        }
+        self.hands_played += 1;
+
+        // Players knocked out in the same round finish in order of the chips
+        // they started the hand with, the bigger stack placing higher
+        busted.sort_by_key(|(bank, _)| *bank);
+        let eliminated: Vec<u8> = busted.iter().rev().map(|(_, player)| player.id()).collect();
+        self.out.extend(busted.into_iter().map(|(_, player)| player));
+
+        let remaining: usize = self.tables.iter().map(|table| table.players().len()).sum();
+        if remaining > 1 {
+            self.balance();
+            return TournamentStatus::Running { eliminated };
+        }
+        let standings = self.final_standings();
+        self.standings = Some(standings.clone());
+        TournamentStatus::Finished(standings)
+    }
+
+    // Breaks tables that are no longer needed and then evens out the rest, so
+    // no table has more than one player more than another. Moved players always
+    // sit down where they post the big blind next.
+    fn balance(&mut self) {
+        let remaining: usize = self.tables.iter().map(|table| table.players().len()).sum();
+        let needed = remaining.div_ceil(self.seats_per_table).max(1);
+        while self.tables.len() > needed {
+            let smallest = self.smallest_table();
+            let mut broken = self.tables.remove(smallest);
+            while !broken.players().is_empty() {
+                let player = broken.remove_player(0).expect("no hand is in progress between hands");
+                self.move_to_smallest(player);
+            }
+        }
+
+        loop {
+            let biggest = (0..self.tables.len()).max_by_key(|&table| self.tables[table].players().len()).unwrap_or(0);
+            let smallest = self.smallest_table();
+            if self.tables[biggest].players().len() <= self.tables[smallest].players().len() + 1 {
+                break;
+            }
+            // The player due the big blind next moves, so they do not skip it
+            let seat = self.tables[biggest].next_positions().big_blind;
+            let player = self.tables[biggest].remove_player(seat).expect("no hand is in progress between hands");
+            self.move_to_smallest(player);
+        }
+    }
+
+    fn smallest_table(&self) -> usize {
+        (0..self.tables.len()).min_by_key(|&table| self.tables[table].players().len()).unwrap_or(0)
+    }
+
+    fn move_to_smallest(&mut self, player: Player) {
+        let smallest = self.smallest_table();
+        self.tables[smallest].seat_player(player).expect("tables are balanced between hands");
+    }
+
+    fn apply_level(&mut self, level: usize) {
+        self.level = level;
+        if let Some(blinds) = self.schedule.levels.get(level) {
+            for table in self.tables.iter_mut() {
+                table.set_blinds(blinds.small_blind, blinds.big_blind);
+                table.set_ante(blinds.ante);
+            }
+        }
+    }
+
+    fn final_standings(&self) -> Vec<Standing> {
+        let winners = self.tables.iter().flat_map(|table| table.players().iter());
+        let order: Vec<&Player> = winners.chain(self.out.iter().rev()).collect();
+        let prizes = self.payouts.prizes(self.prize_pool());
+        order.iter().enumerate()
+            .map(|(i, player)| Standing {
+                place: i + 1,
+                id: player.id(),
+                name: player.name.clone(),
+                prize: prizes.get(i).copied().unwrap_or(Chips::ZERO),
+            })
+            .collect()
+    }
+}
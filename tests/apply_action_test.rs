@@ -0,0 +1,95 @@
+use rust_deck::chips::Chips;
+use rust_deck::event::GameEvent;
+use rust_deck::game::{ActionError, GameResult};
+use rust_deck::player::PlayerAction;
+
+mod common;
+
+use common::table;
+
+#[test]
+fn test_actions_outside_a_hand_are_refused() {
+    let mut game = table(&[1000, 1000, 1000], 7);
+    assert_eq!(game.to_act(), None);
+    assert_eq!(game.apply_action(0, PlayerAction::Check, Chips::ZERO).err(), Some(ActionError::NoHandInProgress));
+}
+
+#[test]
+fn test_out_of_turn_and_illegal_actions() {
+    let mut game = table(&[1000, 1000, 1000], 7);
+    assert!(game.start_hand().is_none());
+    let to_act = game.to_act().unwrap();
+    let other = (to_act + 1) % 3;
+
+    assert!(game.legal_actions(other).actions.is_empty());
+    assert_eq!(game.apply_action(other, PlayerAction::Call, Chips::ZERO).err(), Some(ActionError::OutOfTurn { seat: other, to_act }));
+    // Facing the big blind there is no checking
+    assert_eq!(game.apply_action(to_act, PlayerAction::Check, Chips::ZERO).err(), Some(ActionError::IllegalAction(PlayerAction::Check)));
+    assert_eq!(game.to_act(), Some(to_act));
+}
+
+#[test]
+fn test_min_raise_follows_the_last_raise() {
+    let mut game = table(&[1000, 1000, 1000], 7);
+    assert!(game.start_hand().is_none());
+    let first = game.to_act().unwrap();
+    let legal = game.legal_actions(first);
+    assert_eq!(legal.to_call, Chips::new(50));
+    assert_eq!(legal.min_raise, Chips::new(50));
+    assert_eq!(legal.max_raise, Chips::new(950));
+
+    assert_eq!(
+        game.apply_action(first, PlayerAction::Raise, Chips::new(20)).err(),
+        Some(ActionError::RaiseTooSmall { amount: Chips::new(20), min_raise: Chips::new(50) })
+    );
+    assert_eq!(
+        game.apply_action(first, PlayerAction::Raise, Chips::new(960)).err(),
+        Some(ActionError::RaiseTooLarge { amount: Chips::new(960), max_raise: Chips::new(950) })
+    );
+    assert!(game.apply_action(first, PlayerAction::Raise, Chips::new(150)).unwrap().is_none());
+
+    // The next raise has to be at least as big as the last one
+    let second = game.to_act().unwrap();
+    let legal = game.legal_actions(second);
+    assert_eq!(legal.to_call, Chips::new(175));
+    assert_eq!(legal.min_raise, Chips::new(150));
+    assert!(matches!(game.apply_action(second, PlayerAction::Raise, Chips::new(100)), Err(ActionError::RaiseTooSmall { .. })));
+    assert!(game.apply_action(second, PlayerAction::Raise, Chips::new(200)).unwrap().is_none());
+    assert_eq!(game.legal_actions(game.to_act().unwrap()).min_raise, Chips::new(200));
+}
+
+#[test]
+fn test_short_stack_can_only_go_all_in() {
+    let mut game = table(&[1000, 1000, 70], 7);
+    assert!(game.start_hand().is_none());
+    let short = game.players().iter().position(|player| player.name == "Bot 2").unwrap();
+    while game.to_act() != Some(short) {
+        let seat = game.to_act().unwrap();
+        game.apply_action(seat, PlayerAction::Call, Chips::ZERO).unwrap();
+    }
+    let legal = game.legal_actions(short);
+    assert!(!legal.contains(&PlayerAction::Raise));
+    assert!(legal.contains(&PlayerAction::AllIn));
+    // A raise is refused as illegal whatever its size
+    assert_eq!(
+        game.apply_action(short, PlayerAction::Raise, Chips::new(20)).err(),
+        Some(ActionError::IllegalAction(PlayerAction::Raise))
+    );
+}
+
+#[test]
+fn test_starting_again_keeps_the_hand_in_progress() {
+    let mut game = table(&[1000; 3], 7);
+    let events = game.event_channel();
+    assert!(game.start_hand().is_none());
+    let seat = game.to_act().unwrap();
+    game.apply_action(seat, PlayerAction::Raise, Chips::new(100)).unwrap();
+
+    // Neither call deals a new hand over the chips already in the pot
+    assert!(game.start_hand().is_none());
+    assert_eq!(game.to_act(), Some((seat + 1) % 3));
+    assert!(matches!(game.play_hand(), GameResult::HandFinished(_)));
+    assert_eq!(events.try_iter().filter(|event| matches!(event, GameEvent::HandStarted { .. })).count(), 1);
+    let banks: Chips = game.players().iter().map(|player| player.bank).sum();
+    assert_eq!(banks, Chips::new(3000));
+}
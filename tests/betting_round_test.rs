@@ -0,0 +1,111 @@
+use rust_deck::chips::Chips;
+use rust_deck::event::{GameEvent, Street};
+use rust_deck::game::{ActionError, Game, GameResult};
+use rust_deck::player::{Player, PlayerAction};
+use rust_deck::strategy::{CallingStation, Strategy, TableView};
+
+mod common;
+
+// Raises the first time it acts and calls from then on
+struct RaiseOnce {
+    raised: bool,
+}
+
+impl Strategy for RaiseOnce {
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips) {
+        if !self.raised {
+            self.raised = true;
+            return (PlayerAction::Raise, Chips::new(100));
+        }
+        CallingStation.decide(view)
+    }
+}
+
+fn table(raiser_bank: Chips) -> Vec<Player> {
+    vec![
+        Player::with_strategy(0, String::from("Raiser"), Some(raiser_bank), Box::new(RaiseOnce { raised: false })),
+        Player::with_strategy(1, String::from("Caller 1"), Some(Chips::new(1000)), Box::new(CallingStation)),
+        Player::with_strategy(2, String::from("Caller 2"), Some(Chips::new(1000)), Box::new(CallingStation)),
+    ]
+}
+
+fn preflop_actions(events: &[GameEvent]) -> Vec<(usize, PlayerAction)> {
+    events.iter()
+        .skip_while(|event| !matches!(event, GameEvent::StreetAdvanced { street: Street::PreFlop }))
+        .take_while(|event| !matches!(event, GameEvent::StreetAdvanced { street: Street::Flop }))
+        .filter_map(|event| match event {
+            GameEvent::PlayerActed { seat, action, .. } => Some((*seat, *action)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_raise_reopens_action() {
+    let mut game = Game::with_seed(table(Chips::new(1000)), 3);
+    let events = game.event_channel();
+    game.play_hand();
+    let events: Vec<GameEvent> = events.try_iter().collect();
+
+    let actions = preflop_actions(&events);
+    let raise_at = actions.iter().position(|(_, action)| *action == PlayerAction::Raise).unwrap();
+    let raiser = actions[raise_at].0;
+    // Everybody else answers the raise before the flop, and the raiser is not asked again
+    let after_raise: Vec<usize> = actions[raise_at + 1..].iter().map(|(seat, _)| *seat).collect();
+    assert_eq!(after_raise.len(), 2);
+    assert!(!after_raise.contains(&raiser));
+    assert!(actions[raise_at + 1..].iter().all(|(_, action)| *action == PlayerAction::Call));
+}
+
+#[test]
+fn test_all_in_closes_round() {
+    // The raiser cannot cover the raise and is all in, so the callers keep
+    // checking it down between them.
+    let mut game = Game::with_seed(table(Chips::new(80)), 5);
+    let events = game.event_channel();
+    assert!(matches!(game.play_hand(), GameResult::HandFinished(_)));
+    let events: Vec<GameEvent> = events.try_iter().collect();
+    assert!(events.iter().any(|event| matches!(event, GameEvent::PlayerActed { action: PlayerAction::AllIn, .. })));
+    assert!(events.iter().any(|event| matches!(event, GameEvent::StreetAdvanced { street: Street::Showdown })));
+
+    let total: Chips = game.players().iter().map(|player| player.bank).sum();
+    assert_eq!(total, Chips::new(2080));
+}
+
+#[test]
+fn test_long_session_does_not_recurse() {
+    let mut game = common::table(&[100_000; 4], 11);
+    for _ in 0..2000 {
+        if let GameResult::TableWon { .. } = game.play_hand() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_incomplete_all_in_does_not_reopen_raising() {
+    let mut game = common::table(&[5000, 5000, 700], 2);
+    assert!(game.start_hand().is_none());
+    let raiser = game.to_act().unwrap();
+    let short = game.players().iter().position(|player| player.name == "Bot 2").unwrap();
+    assert_ne!(raiser, short);
+    game.apply_action(raiser, PlayerAction::Raise, Chips::new(550)).unwrap();
+
+    // The short stack shoves for only 100 more than the raise, so the raiser
+    // can call or fold but not raise again
+    while game.to_act() != Some(raiser) {
+        let seat = game.to_act().unwrap();
+        let action = if seat == short { PlayerAction::AllIn } else { PlayerAction::Call };
+        game.apply_action(seat, action, Chips::ZERO).unwrap();
+    }
+    let legal = game.legal_actions(raiser);
+    assert_eq!(legal.to_call, Chips::new(100));
+    assert!(legal.contains(&PlayerAction::Call));
+    assert!(legal.contains(&PlayerAction::Fold));
+    assert!(!legal.contains(&PlayerAction::Raise));
+    assert!(!legal.contains(&PlayerAction::AllIn));
+    assert_eq!(
+        game.apply_action(raiser, PlayerAction::Raise, Chips::new(600)).err(),
+        Some(ActionError::IllegalAction(PlayerAction::Raise))
+    );
+}
@@ -0,0 +1,132 @@
+use rust_deck::betting::{BettingStructure, RaiseLimits};
+use rust_deck::chips::Chips;
+use rust_deck::event::{GameEvent, Street};
+use rust_deck::game::{ActionError, Game};
+use rust_deck::player::PlayerAction;
+use rust_deck::strategy::{Strategy, TableView};
+
+mod common;
+
+const FIXED_LIMIT: BettingStructure = BettingStructure::FixedLimit {
+    small_bet: Chips::new(50),
+    big_bet: Chips::new(100),
+    raise_cap: 4,
+};
+
+// Raises by a single chip every time it acts
+struct Maniac;
+
+impl Strategy for Maniac {
+    fn decide(&mut self, _view: &TableView) -> (PlayerAction, Chips) {
+        (PlayerAction::Raise, Chips::new(1))
+    }
+}
+
+// Tries to go all in every time it acts
+struct Shover;
+
+impl Strategy for Shover {
+    fn decide(&mut self, _view: &TableView) -> (PlayerAction, Chips) {
+        (PlayerAction::AllIn, Chips::ZERO)
+    }
+}
+
+fn table(structure: BettingStructure) -> Game {
+    let mut game = common::table(&[10_000; 3], 13);
+    game.set_betting_structure(structure);
+    game
+}
+
+#[test]
+fn test_raise_limits() {
+    let no_limit = BettingStructure::NoLimit.raise_limits(Street::Flop, Chips::new(50), Chips::new(300), Chips::new(100), 1);
+    assert_eq!(no_limit, Some(RaiseLimits { min: Chips::new(50), max: Chips::MAX }));
+
+    // Call the 100 and then raise the 400 in the middle
+    let pot_limit = BettingStructure::PotLimit.raise_limits(Street::Flop, Chips::new(100), Chips::new(300), Chips::new(100), 1);
+    assert_eq!(pot_limit, Some(RaiseLimits { min: Chips::new(100), max: Chips::new(400) }));
+
+    let small_bet = FIXED_LIMIT.raise_limits(Street::Flop, Chips::new(50), Chips::new(300), Chips::ZERO, 0);
+    assert_eq!(small_bet, Some(RaiseLimits { min: Chips::new(50), max: Chips::new(50) }));
+    let big_bet = FIXED_LIMIT.raise_limits(Street::River, Chips::new(50), Chips::new(300), Chips::ZERO, 3);
+    assert_eq!(big_bet, Some(RaiseLimits { min: Chips::new(100), max: Chips::new(100) }));
+    assert_eq!(FIXED_LIMIT.raise_limits(Street::River, Chips::new(100), Chips::new(300), Chips::new(100), 4), None);
+}
+
+#[test]
+fn test_pot_limit_caps_raises_at_the_pot() {
+    let mut game = table(BettingStructure::PotLimit);
+    assert!(game.start_hand().is_none());
+    let seat = game.to_act().unwrap();
+    let legal = game.legal_actions(seat);
+    // Blinds of 25 and 50, calling 50 makes the pot 125
+    assert_eq!(legal.max_raise, Chips::new(125));
+    assert!(!legal.contains(&PlayerAction::AllIn));
+    assert!(matches!(game.apply_action(seat, PlayerAction::Raise, Chips::new(200)), Err(ActionError::RaiseTooLarge { .. })));
+    assert!(game.apply_action(seat, PlayerAction::Raise, Chips::new(125)).unwrap().is_none());
+    assert_eq!(game.players()[seat].bid, Chips::new(175));
+}
+
+#[test]
+fn test_fixed_limit_bets_and_cap() {
+    let mut game = table(FIXED_LIMIT);
+    assert!(game.start_hand().is_none());
+    // The big blind is the first bet, so three raises cap the street
+    for _ in 0..3 {
+        let seat = game.to_act().unwrap();
+        let legal = game.legal_actions(seat);
+        assert_eq!((legal.min_raise, legal.max_raise), (Chips::new(50), Chips::new(50)));
+        assert!(game.apply_action(seat, PlayerAction::Raise, Chips::new(50)).unwrap().is_none());
+    }
+    let seat = game.to_act().unwrap();
+    let legal = game.legal_actions(seat);
+    assert!(!legal.contains(&PlayerAction::Raise));
+    assert!(!legal.contains(&PlayerAction::AllIn));
+    assert!(legal.contains(&PlayerAction::Call));
+}
+
+#[test]
+fn test_limit_games_play_out() {
+    for structure in [BettingStructure::PotLimit, FIXED_LIMIT] {
+        let mut game = table(structure);
+        for _ in 0..20 {
+            game.play_hand();
+        }
+        let total: Chips = game.players().iter().map(|player| player.bank).sum();
+        assert_eq!(total, Chips::new(30_000));
+    }
+}
+
+#[test]
+fn test_rejected_raises_fall_back_to_calling() {
+    // Undersized raises are made the full bet, and once the street is capped
+    // everyone calls instead of folding
+    let players = (0..3).map(|id| common::player(id, 10_000, Box::new(Maniac))).collect();
+    let mut game = Game::with_seed(players, 13);
+    game.set_betting_structure(FIXED_LIMIT);
+    let events = game.event_channel();
+    game.play_hand();
+    let events: Vec<GameEvent> = events.try_iter().collect();
+
+    assert!(!events.iter().any(|event| matches!(event, GameEvent::PlayerActed { action: PlayerAction::Fold, .. })));
+    assert!(events.iter().any(|event| matches!(event, GameEvent::PlayerActed { action: PlayerAction::Raise, amount, .. } if *amount == Chips::new(100))));
+    assert!(events.iter().any(|event| matches!(event, GameEvent::StreetAdvanced { street: Street::Showdown })));
+}
+
+#[test]
+fn test_illegal_all_in_raises_the_most_allowed() {
+    let players = (0..3).map(|id| common::player(id, 10_000, Box::new(Shover))).collect();
+    let mut game = Game::with_seed(players, 13);
+    game.set_betting_structure(BettingStructure::PotLimit);
+    let events = game.event_channel();
+    game.play_hand();
+    let events: Vec<GameEvent> = events.try_iter().collect();
+
+    assert!(!events.iter().any(|event| matches!(event, GameEvent::PlayerActed { action: PlayerAction::Fold, .. })));
+    // The first shove becomes a pot sized raise, calling 50 and raising 125
+    let first = events.iter().find_map(|event| match event {
+        GameEvent::PlayerActed { action, amount, .. } => Some((*action, *amount)),
+        _ => None,
+    });
+    assert_eq!(first, Some((PlayerAction::Raise, Chips::new(175))));
+}
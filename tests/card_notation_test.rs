@@ -0,0 +1,62 @@
+use rust_deck::deck::{parse_cards, Card, CardParseError, Community, Rank, Suit};
+use rust_deck::hand::Hand;
+
+#[test]
+fn test_card_notation_round_trip() {
+    let ace = Card { suit: Suit::Spades, rank: Rank::Ace };
+    assert_eq!("As".parse::<Card>(), Ok(ace));
+    assert_eq!(ace.to_string(), "As");
+    assert_eq!("td".parse::<Card>(), Ok(Card { suit: Suit::Diamonds, rank: Rank::Ten }));
+    assert_eq!("7h".parse::<Card>().unwrap().to_string(), "7h");
+    assert_eq!("K".parse::<Rank>(), Ok(Rank::King));
+    assert_eq!("c".parse::<Suit>(), Ok(Suit::Clubs));
+    assert_eq!(Rank::Ten.to_string(), "T");
+    assert_eq!(Suit::Hearts.to_string(), "h");
+    assert_eq!(Card { suit: Suit::None, rank: Rank::None }.to_string(), "??");
+
+    assert_eq!("1s".parse::<Card>(), Err(CardParseError::InvalidCard(String::from("1s"))));
+    assert_eq!("Ax".parse::<Card>(), Err(CardParseError::InvalidCard(String::from("Ax"))));
+    assert_eq!("Asd".parse::<Card>(), Err(CardParseError::InvalidCard(String::from("Asd"))));
+    assert!("".parse::<Card>().is_err());
+}
+
+#[test]
+fn test_parse_cards() {
+    let cards = parse_cards("AsKd").unwrap();
+    assert_eq!(cards, [
+        Card { suit: Suit::Spades, rank: Rank::Ace },
+        Card { suit: Suit::Diamonds, rank: Rank::King },
+    ]);
+    assert_eq!(parse_cards("Qc Jc Tc").unwrap().len(), 3);
+    assert_eq!(parse_cards(" QcJc  Tc ").unwrap(), parse_cards("Qc Jc Tc").unwrap());
+    assert_eq!(parse_cards("").unwrap(), []);
+
+    assert_eq!(parse_cards("As As"), Err(CardParseError::Duplicate(Card { suit: Suit::Spades, rank: Rank::Ace })));
+    assert_eq!(parse_cards("AsK"), Err(CardParseError::InvalidCard(String::from("AsK"))));
+    assert_eq!(parse_cards("As Zz"), Err(CardParseError::InvalidCard(String::from("Zz"))));
+}
+
+#[test]
+fn test_parse_hand_and_community() {
+    let hand: Hand = "AsKd".parse().unwrap();
+    assert_eq!(hand.cards, [
+        Card { suit: Suit::Spades, rank: Rank::Ace },
+        Card { suit: Suit::Diamonds, rank: Rank::King },
+    ]);
+    assert_eq!("As".parse::<Hand>().err(), Some(CardParseError::WrongCount { expected: 2, found: 1 }));
+    assert_eq!("As Kd Qh".parse::<Hand>().err(), Some(CardParseError::WrongCount { expected: 2, found: 3 }));
+
+    let flop: Community = "Qc Jc Tc".parse().unwrap();
+    assert_eq!(flop.cards[2], Card { suit: Suit::Clubs, rank: Rank::Ten });
+    assert_eq!(flop.cards[3], Card { suit: Suit::None, rank: Rank::None });
+    assert_eq!(
+        "2c 3c 4c 5c 6c 7c".parse::<Community>().err(),
+        Some(CardParseError::TooManyCards { max: 5, found: 6 }),
+    );
+    assert!("Qc Qc".parse::<Community>().is_err());
+
+    // A parsed hand and board score the same as ones built card by card
+    let mut royal: Hand = "AcKc".parse().unwrap();
+    let board: Community = "4s 5s Qc Jc Tc".parse().unwrap();
+    assert_eq!(royal.hand_value(&board).category, rust_deck::hand::ScoringHands::RoyalFlush);
+}
@@ -0,0 +1,63 @@
+use rust_deck::deck::{parse_cards, Card, CardSet, Deck, Rank, Suit};
+
+fn set(cards: &str) -> CardSet {
+    parse_cards(cards).unwrap().into_iter().collect()
+}
+
+#[test]
+fn test_card_set_operations() {
+    let mut cards = CardSet::EMPTY;
+    let ace: Card = "As".parse().unwrap();
+    assert!(cards.insert(ace));
+    assert!(!cards.insert(ace));
+    assert!(cards.contains(ace));
+    assert_eq!(cards.len(), 1);
+    assert!(!cards.insert(Card { suit: Suit::None, rank: Rank::None }));
+
+    let broadway = set("As Ks Qs Js Ts");
+    let pair = set("Ah As");
+    assert_eq!(broadway | pair, set("As Ks Qs Js Ts Ah"));
+    assert_eq!(broadway & pair, CardSet::from(ace));
+    assert_eq!(broadway.difference(pair), set("Ks Qs Js Ts"));
+    assert_eq!((!broadway).len(), 47);
+    assert_eq!(CardSet::FULL.len(), 52);
+    assert_eq!(CardSet::from_bits(u64::MAX), CardSet::FULL);
+
+    assert!(cards.remove(ace));
+    assert!(!cards.remove(ace));
+    assert!(cards.is_empty());
+}
+
+#[test]
+fn test_card_set_masks_and_iteration() {
+    let cards = set("As Ks 2s 2c Ah");
+    assert_eq!(cards.suit_mask(Suit::Spades), 1 << 12 | 1 << 11 | 1);
+    assert_eq!(cards.suit_mask(Suit::Clubs), 1);
+    assert_eq!(cards.suit_mask(Suit::Diamonds), 0);
+    assert_eq!(cards.rank_mask(), 1 << 12 | 1 << 11 | 1);
+
+    // Every card converts to a bit and back
+    let mut deck = Deck::new();
+    let all: Vec<Card> = (0..52).map(|_| deck.deal()).collect();
+    let full: CardSet = all.iter().copied().collect();
+    assert_eq!(full, CardSet::FULL);
+    assert_eq!(full.iter().count(), 52);
+    for card in full {
+        assert!(all.contains(&card));
+        assert_eq!(CardSet::from(card).iter().next(), Some(card));
+    }
+
+    let order: Vec<Card> = cards.iter().collect();
+    assert_eq!(order, parse_cards("2c Ah 2s Ks As").unwrap());
+}
+
+#[test]
+fn test_deck_undealt() {
+    let mut deck = Deck::new();
+    deck.shuffle();
+    assert_eq!(deck.undealt(), CardSet::FULL);
+    let first = deck.deal();
+    let undealt = deck.undealt();
+    assert_eq!(undealt.len(), 51);
+    assert!(!undealt.contains(first));
+}
@@ -0,0 +1,73 @@
+use rust_deck::cash::{BuyIn, CashError, CashTable};
+use rust_deck::chips::Chips;
+use rust_deck::event::GameEvent;
+use rust_deck::game::{GameResult, SeatError};
+
+mod common;
+
+use common::bot;
+
+const BUY_IN: BuyIn = BuyIn { min: Chips::new(2000), max: Chips::new(10_000) };
+
+fn table() -> CashTable {
+    CashTable::new(common::table(&[5000; 3], 37), BUY_IN, 4)
+}
+
+fn seat_of(table: &CashTable, id: u8) -> usize {
+    table.game().players().iter().position(|player| player.id() == id).unwrap()
+}
+
+#[test]
+fn test_join_and_leave_within_limits() {
+    let mut table = table();
+    assert!(matches!(table.join(bot(3, 5000), Chips::new(500)), Err(CashError::BuyInOutOfRange { .. })));
+    let seat = table.join(bot(3, 5000), Chips::new(4000)).unwrap();
+    assert_eq!(table.game().players()[seat].bank, Chips::new(4000));
+    assert_eq!(table.join(bot(4, 5000), Chips::new(4000)), Err(CashError::Seat(SeatError::TableFull)));
+
+    assert!(matches!(table.play_hand(), GameResult::HandFinished(_)));
+    let seat = seat_of(&table, 3);
+    let bank = table.game().players()[seat].bank;
+    let left = table.leave(seat).unwrap();
+    assert_eq!((left.id(), left.bank), (3, bank));
+    assert_eq!(table.game().players().len(), 3);
+}
+
+#[test]
+fn test_rebuy_and_top_up() {
+    let mut table = table();
+    let seat = seat_of(&table, 0);
+    assert_eq!(table.rebuy(seat, Chips::new(3000)), Err(CashError::StillHasChips(seat)));
+    assert!(matches!(table.top_up(seat, Chips::new(6000)), Err(CashError::BuyInOutOfRange { .. })));
+    table.top_up(seat, Chips::new(5000)).unwrap();
+    assert_eq!(table.game().players()[seat].bank, Chips::new(10_000));
+
+    table.game_mut().player_mut(seat).unwrap().bank = Chips::ZERO;
+    assert_eq!(table.top_up(seat, Chips::new(1000)), Err(CashError::Busted(seat)));
+    table.rebuy(seat, Chips::new(2000)).unwrap();
+    assert_eq!(table.game().players()[seat].bank, Chips::new(2000));
+}
+
+#[test]
+fn test_sitting_out_keeps_the_seat() {
+    let mut table = table();
+    let seat = seat_of(&table, 1);
+    table.sit_out(seat).unwrap();
+    let events = table.game_mut().event_channel();
+    for _ in 0..5 {
+        assert!(matches!(table.play_hand(), GameResult::HandFinished(_)));
+    }
+    let player = &table.game().players()[seat];
+    assert_eq!((player.id(), player.bank), (1, Chips::new(5000)));
+    assert!(events.try_iter().all(|event| match event {
+        GameEvent::HoleCardsDealt { seat: dealt, .. } => dealt != seat,
+        _ => true,
+    }));
+
+    // Alone at the table nothing is dealt until someone sits back in
+    let other = (0..3).find(|&other| other != seat).unwrap();
+    table.sit_out(other).unwrap();
+    assert!(matches!(table.play_hand(), GameResult::Waiting));
+    table.sit_in(seat).unwrap();
+    assert!(matches!(table.play_hand(), GameResult::HandFinished(_)));
+}
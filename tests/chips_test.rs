@@ -0,0 +1,26 @@
+use rust_deck::chips::Chips;
+
+#[test]
+fn test_chips_arithmetic() {
+    let blind = Chips::new(25);
+    let mut pot = Chips::ZERO;
+    for _ in 0..1000 {
+        pot += blind;
+    }
+    assert_eq!(pot, Chips::from_dollars(250));
+    assert_eq!(pot - blind * 4, Chips::new(24_900));
+
+    assert_eq!(blind.checked_sub(Chips::new(26)), None);
+    assert_eq!(Chips::new(u64::MAX).checked_add(Chips::new(1)), None);
+    assert_eq!(blind.saturating_sub(Chips::new(26)), Chips::ZERO);
+    assert_eq!(Chips::new(100).split(3), (Chips::new(33), Chips::new(1)));
+}
+
+#[test]
+fn test_chips_formatting() {
+    assert_eq!(Chips::new(125).to_string(), "125");
+    assert_eq!(Chips::new(125).dollars().to_string(), "$1.25");
+    assert_eq!(Chips::new(5).dollars().to_string(), "$0.05");
+    assert_eq!(" 300 ".parse::<Chips>(), Ok(Chips::new(300)));
+    assert!("1.5".parse::<Chips>().is_err());
+}
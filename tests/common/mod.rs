@@ -0,0 +1,27 @@
+// Builders shared by the integration tests. Every test file only uses some of them.
+#![allow(dead_code)]
+
+use rust_deck::chips::Chips;
+use rust_deck::game::Game;
+use rust_deck::player::Player;
+use rust_deck::strategy::{CallingStation, Strategy};
+
+// A player named "Bot <id>" who plays `strategy`
+pub fn player(id: u8, bank: u64, strategy: Box<dyn Strategy>) -> Player {
+    Player::with_strategy(id, format!("Bot {}", id), Some(Chips::new(bank)), strategy)
+}
+
+// A calling station
+pub fn bot(id: u8, bank: u64) -> Player {
+    player(id, bank, Box::new(CallingStation))
+}
+
+// Calling stations seated in order, one for each bank
+pub fn bots(banks: &[u64]) -> Vec<Player> {
+    banks.iter().enumerate().map(|(i, &bank)| bot(i as u8, bank)).collect()
+}
+
+// A seeded table of calling stations, one for each bank
+pub fn table(banks: &[u64], seed: u64) -> Game {
+    Game::with_seed(bots(banks), seed)
+}
@@ -0,0 +1,61 @@
+use rust_deck::betting::{Ante, BettingStructure};
+use rust_deck::chips::Chips;
+use rust_deck::config::{ConfigError, GameConfig, Variant};
+use rust_deck::game::Game;
+use rust_deck::strategy::CallingStation;
+
+#[test]
+fn test_builder() {
+    let config = GameConfig::default()
+        .blinds(Chips::new(50), Chips::new(100))
+        .starting_stack(Chips::new(5000))
+        .players(["Ann", "Bo", "Cy"])
+        .ante(Ante::BigBlind(Chips::new(100)))
+        .seed(21);
+    assert!(config.validate().is_ok());
+
+    let game = Game::from_config_with(&config, |_| Box::new(CallingStation));
+    assert_eq!(game.seed(), 21);
+    let mut names: Vec<&str> = game.players().iter().map(|player| player.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, ["Ann", "Bo", "Cy"]);
+    assert!(game.players().iter().all(|player| player.bank == Chips::new(5000)));
+}
+
+#[test]
+fn test_toml_and_json_configs() {
+    let toml = r#"
+        small_blind = 10
+        big_blind = 20
+        players = ["Ann", "Bo"]
+        seed = 4
+
+        [betting.fixed_limit]
+        small_bet = 20
+        big_bet = 40
+        raise_cap = 4
+    "#;
+    let config = GameConfig::from_toml(toml).unwrap();
+    assert_eq!(config.variant, Variant::TexasHoldem);
+    assert_eq!(config.big_blind, Chips::new(20));
+    assert_eq!(config.starting_stack, GameConfig::default().starting_stack);
+    assert_eq!(config.betting, BettingStructure::FixedLimit { small_bet: Chips::new(20), big_bet: Chips::new(40), raise_cap: 4 });
+
+    let json = r#"{ "variant": "texas_holdem", "players": ["Ann", "Bo", "Cy"], "betting": "pot_limit", "ante": { "per_player": 5 } }"#;
+    let config = GameConfig::from_json(json).unwrap();
+    assert_eq!(config.betting, BettingStructure::PotLimit);
+    assert_eq!(config.ante, Ante::PerPlayer(Chips::new(5)));
+    assert_eq!(config.seed, None);
+
+    // Round trip what the builder makes
+    let built = GameConfig::default().straddle(true).seed(9);
+    assert_eq!(GameConfig::from_toml(&toml::to_string(&built).unwrap()).unwrap(), built);
+}
+
+#[test]
+fn test_invalid_configs() {
+    assert!(matches!(GameConfig::from_toml(r#"players = ["Alone"]"#), Err(ConfigError::Invalid(_))));
+    assert!(matches!(GameConfig::from_toml("small_blind = 50\nbig_blind = 25"), Err(ConfigError::Invalid(_))));
+    assert!(matches!(GameConfig::from_json("{ \"betting\": \"no_such_game\" }"), Err(ConfigError::Parse(_))));
+    assert!(matches!(GameConfig::load("table.yaml"), Err(ConfigError::Io(_))));
+}
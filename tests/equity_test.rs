@@ -0,0 +1,69 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_deck::deck::{parse_cards, Card};
+use rust_deck::equity::{exhaustive, monte_carlo, EquityError};
+use rust_deck::hand::Hand;
+
+fn hands(hands: &[&str]) -> Vec<[Card; 2]> {
+    hands.iter().map(|hand| hand.parse::<Hand>().unwrap().cards).collect()
+}
+
+#[test]
+fn test_monte_carlo_equity() {
+    let aces_kings = hands(&["AsAh", "KsKh"]);
+    let results = monte_carlo(&aces_kings, &[], &[], 5000, &mut StdRng::seed_from_u64(25)).unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].runouts(), 5000);
+    // Aces are about an 82% favourite over kings
+    assert!((results[0].equity_percent() - 82.0).abs() < 3.0, "{:?}", results);
+    assert_eq!(results[0].wins, results[1].losses);
+    assert_eq!(results[0].ties, results[1].ties);
+    let total = results[0].win_percent() + results[0].tie_percent() + results[0].loss_percent();
+    assert!((total - 100.0).abs() < 1e-9);
+
+    // The same seed deals the same runouts
+    let again = monte_carlo(&aces_kings, &[], &[], 5000, &mut StdRng::seed_from_u64(25)).unwrap();
+    assert_eq!(results, again);
+
+    // With both remaining kings dead, kings can only win on a straight or flush
+    let board = parse_cards("2c 7d").unwrap();
+    let dead = parse_cards("Kc Kd").unwrap();
+    let results = monte_carlo(&aces_kings, &board, &dead, 2000, &mut StdRng::seed_from_u64(25)).unwrap();
+    assert!(results[1].equity_percent() < 10.0, "{:?}", results);
+}
+
+#[test]
+fn test_exhaustive_equity() {
+    // On the river there is only one runout
+    let board = parse_cards("2c 7d Th 3s 4s").unwrap();
+    let results = exhaustive(&hands(&["AsKd", "QhQc"]), &board, &[]).unwrap();
+    assert_eq!((results[0].losses, results[1].wins), (1, 1));
+
+    // Both hands play the board straight and split every runout
+    let board = parse_cards("6c 7d 8h 9s").unwrap();
+    let results = exhaustive(&hands(&["2c 3d", "2h 3s"]), &board, &[]).unwrap();
+    assert_eq!(results[0].runouts(), 44);
+    assert!(results[0].ties > 0);
+    assert_eq!(results[0].wins, results[1].wins);
+    assert!((results[0].equity_percent() - 50.0).abs() < 1e-9);
+
+    // Sampling comes close to dealing every runout from the flop
+    let board = parse_cards("Qs Jd 4c").unwrap();
+    let exact = exhaustive(&hands(&["AsKd", "QhQc", "9h 8h"]), &board, &[]).unwrap();
+    assert_eq!(exact[0].runouts(), 903);
+    let sampled = monte_carlo(&hands(&["AsKd", "QhQc", "9h 8h"]), &board, &[], 4000, &mut StdRng::seed_from_u64(1)).unwrap();
+    for (exact, sampled) in exact.iter().zip(sampled.iter()) {
+        assert!((exact.equity_percent() - sampled.equity_percent()).abs() < 3.0);
+    }
+}
+
+#[test]
+fn test_equity_errors() {
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(monte_carlo(&hands(&["AsKd"]), &[], &[], 10, &mut rng), Err(EquityError::TooFewHands));
+    let ace = "As".parse::<Card>().unwrap();
+    assert_eq!(exhaustive(&hands(&["AsKd", "AsQc"]), &[], &[]), Err(EquityError::Duplicate(ace)));
+    assert_eq!(exhaustive(&hands(&["AsKd", "QhQc"]), &[], &[ace]), Err(EquityError::Duplicate(ace)));
+    let board = parse_cards("2c 3c 4c 5c 6c 7c").unwrap();
+    assert_eq!(exhaustive(&hands(&["AsKd", "QhQc"]), &board, &[]), Err(EquityError::TooManyBoardCards(6)));
+}
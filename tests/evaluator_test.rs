@@ -0,0 +1,50 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_deck::deck::{parse_cards, Card, CardSet, Deck, Rank};
+use rust_deck::evaluator::{evaluate, evaluate_cards, HandValue};
+use rust_deck::hand::{self, HandRank, ScoringHands};
+
+fn value(cards: &str) -> HandValue {
+    evaluate_cards(&parse_cards(cards).unwrap())
+}
+
+#[test]
+fn test_evaluator_matches_reference() {
+    let mut rng = StdRng::seed_from_u64(24);
+    let mut deck = Deck::new();
+    for _ in 0..5000 {
+        deck.shuffle_with(&mut rng);
+        let cards: Vec<Card> = (0..7).map(|_| deck.deal()).collect();
+        for count in 5..=7 {
+            let cards = &cards[..count];
+            assert_eq!(evaluate_cards(cards).rank(), hand::evaluate(cards), "{:?}", cards);
+        }
+    }
+}
+
+#[test]
+fn test_evaluator_categories() {
+    let hands = [
+        ("2c 7d 9h Js Kc 3d 4h", ScoringHands::HighCard),
+        ("2c 2d 9h Js Kc 3d 4h", ScoringHands::OnePair),
+        ("2c 2d 9h 9s Kc Kd 4h", ScoringHands::TwoPair),
+        ("2c 2d 2h Js Kc 3d 4h", ScoringHands::ThreeOfAKind),
+        ("Ac 2d 3h 4s 5c Kd Kh", ScoringHands::Straight),
+        ("2h 7h 9h Jh Kh 3d 4h", ScoringHands::Flush),
+        ("2c 2d 2h Js Jc Jd 4h", ScoringHands::FullHouse),
+        ("2c 2d 2h 2s Kc 3d 4h", ScoringHands::FourOfAKind),
+        ("5h 6h 7h 8h 9h Th As", ScoringHands::StraightFlush),
+        ("Ts Js Qs Ks As 2c 2d", ScoringHands::RoyalFlush),
+    ];
+    let values: Vec<HandValue> = hands.iter().map(|(cards, _)| value(cards)).collect();
+    for ((cards, category), value) in hands.iter().zip(values.iter()) {
+        assert_eq!(value.category(), *category, "{}", cards);
+    }
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Jacks full of twos, not twos full of jacks
+    assert_eq!(value("2c 2d 2h Js Jc Jd 4h").rank(), HandRank::new(ScoringHands::FullHouse, &[Rank::Jack, Rank::Two]));
+    assert_eq!(value("Ac 2d 3h 4s 5c Kd Kh"), value("5d 4c 3c 2c Ah"));
+    assert_eq!(evaluate(CardSet::EMPTY).rank(), HandRank::default());
+    assert!(value("As Ks Qs Js 9d 2c 3c") > value("As Ks Qs Js 8d 7c 3c"));
+}
@@ -0,0 +1,60 @@
+use rust_deck::chips::Chips;
+use rust_deck::event::{ForcedBet, GameEvent, Street};
+use rust_deck::game::{Game, GameResult};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod common;
+
+use common::bots;
+
+#[test]
+fn test_hand_events() {
+    let mut game = Game::with_players(bots(&[1000; 3]));
+    let events: Rc<RefCell<Vec<GameEvent>>> = Rc::new(RefCell::new(Vec::new()));
+    let recorded = events.clone();
+    game.subscribe(move |event| recorded.borrow_mut().push(event.clone()));
+    let receiver = game.event_channel();
+
+    assert!(matches!(game.play_hand(), GameResult::HandFinished(_)));
+
+    let events = events.borrow();
+    assert!(matches!(events[0], GameEvent::HandStarted { hand_number: 1, .. }));
+    let blinds: Vec<ForcedBet> = events.iter().filter_map(|event| match event {
+        GameEvent::BlindPosted { kind, .. } => Some(*kind),
+        _ => None,
+    }).collect();
+    assert_eq!(blinds, vec![ForcedBet::SmallBlind, ForcedBet::BigBlind]);
+    assert_eq!(events.iter().filter(|event| matches!(event, GameEvent::HoleCardsDealt { .. })).count(), 3);
+
+    // Calling stations always see every street
+    let streets: Vec<Street> = events.iter().filter_map(|event| match event {
+        GameEvent::StreetAdvanced { street } => Some(*street),
+        _ => None,
+    }).collect();
+    assert_eq!(streets, vec![Street::PreFlop, Street::Flop, Street::Turn, Street::River, Street::Showdown]);
+
+    let awarded: Chips = events.iter().map(|event| match event {
+        GameEvent::PotAwarded { amount, .. } => *amount,
+        _ => Chips::ZERO,
+    }).sum();
+    let put_in: Chips = events.iter().map(|event| match event {
+        GameEvent::BlindPosted { amount, .. } | GameEvent::PlayerActed { amount, .. } => *amount,
+        _ => Chips::ZERO,
+    }).sum();
+    assert_eq!(awarded, put_in);
+
+    // The channel sees the same events as the callback
+    assert_eq!(receiver.try_iter().collect::<Vec<GameEvent>>(), *events);
+}
+
+#[test]
+fn test_events_round_trip_as_json() {
+    let mut game = Game::with_seed(bots(&[1000; 3]), 8);
+    let events = game.event_channel();
+    game.play_hand();
+    for event in events.try_iter() {
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<GameEvent>(&json).unwrap(), event);
+    }
+}
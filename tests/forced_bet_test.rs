@@ -0,0 +1,121 @@
+use rust_deck::betting::Ante;
+use rust_deck::chips::Chips;
+use rust_deck::deck::Rank;
+use rust_deck::event::{ForcedBet, GameEvent, Street};
+use rust_deck::player::PlayerAction;
+use std::sync::mpsc::Receiver;
+
+mod common;
+
+use common::table;
+
+fn forced_bets(events: &Receiver<GameEvent>) -> Vec<(usize, ForcedBet, Chips)> {
+    events.try_iter()
+        .filter_map(|event| match event {
+            GameEvent::BlindPosted { seat, kind, amount } => Some((seat, kind, amount)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_antes_are_dead_money() {
+    let mut game = table(&[10_000; 4], 17);
+    game.set_ante(Ante::PerPlayer(Chips::new(10)));
+    let events = game.event_channel();
+    assert!(game.start_hand().is_none());
+
+    let posted = forced_bets(&events);
+    assert_eq!(posted.iter().filter(|(_, kind, _)| *kind == ForcedBet::Ante).count(), 4);
+    // Antes do not count towards the bid, so the big blind still has the option
+    let big_blind = game.positions().big_blind;
+    assert_eq!(game.players()[big_blind].bid, Chips::new(50));
+    assert_eq!(game.players()[big_blind].contributed, Chips::new(60));
+    assert_eq!(game.table_view(big_blind).pot, Chips::new(115));
+}
+
+#[test]
+fn test_big_blind_ante() {
+    let mut game = table(&[10_000; 4], 17);
+    game.set_ante(Ante::BigBlind(Chips::new(50)));
+    let events = game.event_channel();
+    assert!(game.start_hand().is_none());
+
+    let big_blind = game.positions().big_blind;
+    let posted = forced_bets(&events);
+    assert_eq!(posted.last(), Some(&(big_blind, ForcedBet::BigBlindAnte, Chips::new(50))));
+    assert_eq!(game.players()[big_blind].bid, Chips::new(50));
+    assert_eq!(game.players()[big_blind].contributed, Chips::new(100));
+}
+
+#[test]
+fn test_straddler_acts_last() {
+    let mut game = table(&[10_000; 4], 17);
+    game.set_straddle(true);
+    let events = game.event_channel();
+    game.play_hand();
+    let events: Vec<GameEvent> = events.try_iter().collect();
+
+    let straddler = events.iter().find_map(|event| match event {
+        GameEvent::BlindPosted { seat, kind: ForcedBet::Straddle, amount } => {
+            assert_eq!(*amount, Chips::new(100));
+            Some(*seat)
+        },
+        _ => None,
+    }).unwrap();
+    let preflop: Vec<(usize, PlayerAction)> = events.iter()
+        .take_while(|event| !matches!(event, GameEvent::StreetAdvanced { street: Street::Flop }))
+        .filter_map(|event| match event {
+            GameEvent::PlayerActed { seat, action, .. } => Some((*seat, *action)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(preflop.len(), 4);
+    assert_eq!(preflop.last(), Some(&(straddler, PlayerAction::Check)));
+}
+
+#[test]
+fn test_short_straddle_sets_the_bet_to_what_was_posted() {
+    let mut game = table(&[10_000, 10_000, 10_000, 70], 0);
+    game.set_straddle(true);
+    let events = game.event_channel();
+    assert!(game.start_hand().is_none());
+    let posted = forced_bets(&events);
+    let &(straddler, _, amount) = posted.iter().find(|(_, kind, _)| *kind == ForcedBet::Straddle).unwrap();
+    assert_eq!(game.players()[straddler].name, "Bot 3");
+    assert_eq!(amount, Chips::new(70));
+
+    // The next player only has to call the 70, and the minimum raise is still
+    // the big blind
+    let seat = game.to_act().unwrap();
+    let legal = game.legal_actions(seat);
+    assert_eq!(legal.to_call, Chips::new(70));
+    assert_eq!(legal.min_raise, Chips::new(50));
+}
+
+#[test]
+fn test_bomb_pot_starts_on_the_flop() {
+    let mut game = table(&[10_000; 3], 17);
+    game.schedule_bomb_pot(Chips::new(200));
+    let events = game.event_channel();
+    assert!(game.start_hand().is_none());
+    let posted = forced_bets(&events);
+    assert_eq!(posted.len(), 3);
+    assert!(posted.iter().all(|(_, kind, amount)| *kind == ForcedBet::BombPot && *amount == Chips::new(200)));
+
+    let seat = game.to_act().unwrap();
+    let view = game.table_view(seat);
+    assert_eq!(view.pot, Chips::new(600));
+    assert!(view.legal_actions.contains(&PlayerAction::Check));
+    assert_eq!(view.community.cards.iter().filter(|card| card.rank != Rank::None).count(), 3);
+
+    // Only the one hand is a bomb pot
+    while let Some(seat) = game.to_act() {
+        game.apply_action(seat, PlayerAction::Check, Chips::ZERO).unwrap();
+    }
+    let banks: Chips = game.players().iter().map(|player| player.bank).sum();
+    assert_eq!(banks, Chips::new(30_000));
+    game.play_hand();
+    let posted = forced_bets(&events);
+    assert!(posted.iter().all(|(_, kind, _)| *kind != ForcedBet::BombPot));
+}
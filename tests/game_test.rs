@@ -0,0 +1,27 @@
+use rust_deck::chips::Chips;
+use rust_deck::game::{Game, GameResult};
+
+mod common;
+
+use common::bots;
+
+#[test]
+fn test_headless_game_returns_results() {
+    let mut game = Game::with_players(bots(&[1000; 3]));
+    let total: Chips = game.players().iter().map(|player| player.bank).sum();
+
+    for _ in 0..200 {
+        match game.play_hand() {
+            GameResult::HandFinished(result) => {
+                assert!(!result.payouts.is_empty());
+                let banks: Chips = game.players().iter().map(|player| player.bank).sum();
+                assert_eq!(banks, total);
+            },
+            GameResult::TableWon { seat, .. } => {
+                assert_eq!(game.players()[seat].bank, total);
+                return;
+            },
+            GameResult::Waiting => panic!("nobody is sitting out"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use rust_deck::hand::{Hand, ScoringHands};
+use rust_deck::hand::{best_hand, evaluate, Hand, HandRank, ScoringHands};
 use rust_deck::deck::{Community, Card, Suit, Rank};
 
 #[test]
@@ -11,7 +11,7 @@ fn test_hand_value() {
             suit: Suit::Clubs,
             rank: Rank::King,
         }],
-        value: (ScoringHands::None, 0),
+        value: HandRank::default(),
     };
     let mut hand_2 = Hand {
         cards: [Card {
@@ -21,10 +21,10 @@ fn test_hand_value() {
             suit: Suit::Spades,
             rank: Rank::Seven,
         }],
-        value: (ScoringHands::None, 0),
+        value: HandRank::default(),
     };
 
-    let mut community = Community{
+    let community = Community{
         cards: [Card {
             suit: Suit::Spades,
             rank: Rank::Four,
@@ -44,8 +44,88 @@ fn test_hand_value() {
     };
 
     let hand_value = hand_1.hand_value(&community);
-    assert_eq!(hand_value, (ScoringHands::RoyalFlush, Rank::Ace as u8));
+    assert_eq!(hand_value, HandRank::new(ScoringHands::RoyalFlush, &[Rank::Ace]));
 
     let hand_value2 = hand_2.hand_value(&community);
-    assert_eq!(hand_value2, (ScoringHands::HighCard, Rank::Queen as u8));
+    assert_eq!(hand_value2, HandRank::new(ScoringHands::HighCard, &[Rank::Queen, Rank::Jack, Rank::Ten, Rank::Seven, Rank::Five]));
+}
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+#[test]
+fn test_hand_rank_kickers() {
+    // Two pair is decided by the second pair, then the kicker
+    let kings_and_fours = evaluate(&[
+        card(Suit::Clubs, Rank::King), card(Suit::Hearts, Rank::King),
+        card(Suit::Spades, Rank::Four), card(Suit::Diamonds, Rank::Four),
+        card(Suit::Clubs, Rank::Ace), card(Suit::Hearts, Rank::Two), card(Suit::Spades, Rank::Seven),
+    ]);
+    let kings_and_threes = evaluate(&[
+        card(Suit::Clubs, Rank::King), card(Suit::Hearts, Rank::King),
+        card(Suit::Spades, Rank::Three), card(Suit::Diamonds, Rank::Three),
+        card(Suit::Clubs, Rank::Ace), card(Suit::Hearts, Rank::Two), card(Suit::Spades, Rank::Seven),
+    ]);
+    assert_eq!(kings_and_fours, HandRank::new(ScoringHands::TwoPair, &[Rank::King, Rank::Four, Rank::Ace]));
+    assert!(kings_and_fours > kings_and_threes);
+
+    // Flushes are compared card by card
+    let flush_nine = evaluate(&[
+        card(Suit::Hearts, Rank::Ace), card(Suit::Hearts, Rank::Jack), card(Suit::Hearts, Rank::Eight),
+        card(Suit::Hearts, Rank::Six), card(Suit::Hearts, Rank::Two),
+    ]);
+    let flush_seven = evaluate(&[
+        card(Suit::Spades, Rank::Ace), card(Suit::Spades, Rank::Jack), card(Suit::Spades, Rank::Eight),
+        card(Suit::Spades, Rank::Five), card(Suit::Spades, Rank::Two),
+    ]);
+    assert!(flush_nine > flush_seven);
+
+    // Same pair, same kickers on the board is an exact tie
+    let board = [
+        card(Suit::Clubs, Rank::Queen), card(Suit::Hearts, Rank::Queen), card(Suit::Spades, Rank::Ace),
+        card(Suit::Diamonds, Rank::King), card(Suit::Clubs, Rank::Jack),
+    ];
+    let mut hand_1 = board.to_vec();
+    hand_1.extend([card(Suit::Hearts, Rank::Two), card(Suit::Spades, Rank::Three)]);
+    let mut hand_2 = board.to_vec();
+    hand_2.extend([card(Suit::Diamonds, Rank::Four), card(Suit::Clubs, Rank::Five)]);
+    assert_eq!(evaluate(&hand_1), evaluate(&hand_2));
+
+    // The wheel is the lowest straight
+    let wheel = evaluate(&[
+        card(Suit::Clubs, Rank::Ace), card(Suit::Hearts, Rank::Two), card(Suit::Spades, Rank::Three),
+        card(Suit::Diamonds, Rank::Four), card(Suit::Clubs, Rank::Five), card(Suit::Clubs, Rank::King),
+    ]);
+    assert_eq!(wheel, HandRank::new(ScoringHands::Straight, &[Rank::Five]));
+}
+
+#[test]
+fn test_best_hand_description() {
+    let hand = Hand {
+        cards: [card(Suit::Clubs, Rank::King), card(Suit::Spades, Rank::Four)],
+        value: HandRank::default(),
+    };
+    let community = Community {
+        cards: [
+            card(Suit::Hearts, Rank::King), card(Suit::Diamonds, Rank::Four), card(Suit::Clubs, Rank::Ace),
+            card(Suit::Hearts, Rank::Two), card(Suit::Hearts, Rank::Two),
+        ],
+    };
+
+    let best = hand.best_hand(&community);
+    assert_eq!(best.to_string(), "Two Pair, Kings and Fours, Ace kicker");
+    assert_eq!(best.cards, [
+        card(Suit::Clubs, Rank::King), card(Suit::Hearts, Rank::King),
+        card(Suit::Spades, Rank::Four), card(Suit::Diamonds, Rank::Four),
+        card(Suit::Clubs, Rank::Ace),
+    ]);
+
+    let full_house = best_hand(&[
+        card(Suit::Clubs, Rank::Six), card(Suit::Hearts, Rank::Six), card(Suit::Spades, Rank::Six),
+        card(Suit::Clubs, Rank::Ten), card(Suit::Hearts, Rank::Ten), card(Suit::Spades, Rank::Ten),
+        card(Suit::Diamonds, Rank::Ace),
+    ]);
+    assert_eq!(full_house.to_string(), "Full House, Tens full of Sixes");
+    assert_eq!(full_house.cards.iter().filter(|card| card.rank == Rank::Six).count(), 2);
 }
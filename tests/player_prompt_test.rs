@@ -1,5 +1,27 @@
-use rust_deck::player::Player;
-use rust_deck::deck;
+use rust_deck::chips::Chips;
+use rust_deck::deck::{self, Community};
+use rust_deck::player::{Player, PlayerAction};
+use rust_deck::strategy::{CallingStation, LegalActions, Prompt, Strategy, TableView};
+
+fn view_for(player: &Player, current_bid: Chips, actions: Vec<PlayerAction>) -> TableView {
+    let to_call = current_bid.saturating_sub(player.bid);
+    TableView {
+        seat: 0,
+        name: player.name.clone(),
+        hand: player.hand,
+        community: Community::new(),
+        pot: current_bid,
+        current_bid,
+        bid: player.bid,
+        bank: player.bank,
+        legal_actions: LegalActions {
+            actions,
+            to_call,
+            min_raise: Chips::new(50),
+            max_raise: player.bank.saturating_sub(to_call),
+        },
+    }
+}
 
 #[test]
 fn test_player_prompt() {
@@ -12,6 +34,39 @@ fn test_player_prompt() {
     let mut output = Vec::new();
     let input = b"fold";
 
-    let action = player.prompt_action(&input[..], &mut output, None);
-    assert_eq!(action, (rust_deck::player::PlayerAction::Fold, 0.0));
-}
\ No newline at end of file
+    let view = view_for(&player, Chips::ZERO, vec![PlayerAction::Fold, PlayerAction::Check, PlayerAction::Raise, PlayerAction::AllIn]);
+    let action = Prompt::new(&input[..], &mut output).decide(&view);
+    assert_eq!(action, (PlayerAction::Fold, Chips::ZERO));
+}
+
+#[test]
+fn test_player_prompt_reprompts_illegal_actions() {
+    let player = Player::new(0, String::from("Player 0"), None);
+    let mut output = Vec::new();
+    let input = b"check\nraise abc\nraise 20\nraise 50\n";
+
+    let view = view_for(&player, Chips::new(50), vec![PlayerAction::Fold, PlayerAction::Call, PlayerAction::Raise, PlayerAction::AllIn]);
+    let action = Prompt::new(&input[..], &mut output).decide(&view);
+    assert_eq!(action, (PlayerAction::Raise, Chips::new(50)));
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Cannot check"));
+    assert!(output.contains("Invalid amount"));
+    assert!(output.contains("Raise too small"));
+
+    let bot_action = CallingStation.decide(&view);
+    assert_eq!(bot_action, (PlayerAction::Call, Chips::ZERO));
+}
+
+#[test]
+fn test_player_prompt_reprompts_an_illegal_all_in() {
+    let player = Player::new(0, String::from("Player 0"), None);
+    let mut output = Vec::new();
+    let input = b"all-in\ncall\n";
+
+    // Like a pot limit game, where the whole bank is more than a legal raise
+    let view = view_for(&player, Chips::new(50), vec![PlayerAction::Fold, PlayerAction::Call, PlayerAction::Raise]);
+    let action = Prompt::new(&input[..], &mut output).decide(&view);
+    assert_eq!(action, (PlayerAction::Call, Chips::ZERO));
+    assert!(String::from_utf8(output).unwrap().contains("Cannot go all-in"));
+}
@@ -0,0 +1,61 @@
+use rust_deck::event::{ForcedBet, GameEvent, Street};
+
+mod common;
+
+use common::table;
+
+#[test]
+fn test_button_moves_and_skips_busted_seats() {
+    let mut game = table(&[1000, 1000, 0, 1000], 1);
+    let busted = game.players().iter().position(|player| player.bank.is_zero()).unwrap();
+
+    let mut buttons = Vec::new();
+    for _ in 0..6 {
+        game.play_hand();
+        let positions = game.positions();
+        assert_ne!(positions.button, busted);
+        assert_ne!(positions.small_blind, busted);
+        assert_ne!(positions.big_blind, busted);
+        assert_ne!(positions.under_the_gun, busted);
+        assert_eq!(positions.under_the_gun, positions.button);
+        buttons.push(positions.button);
+    }
+    // Every live seat gets the button once per orbit
+    let mut orbit = buttons[..3].to_vec();
+    orbit.sort();
+    orbit.dedup();
+    assert_eq!(orbit.len(), 3);
+    assert_eq!(buttons[..3], buttons[3..]);
+}
+
+#[test]
+fn test_heads_up_button_posts_small_blind_and_acts_first() {
+    let mut game = table(&[1000, 1000], 2);
+    let events = game.event_channel();
+    game.play_hand();
+    let events: Vec<GameEvent> = events.try_iter().collect();
+    let button = game.positions().button;
+
+    let small_blind = events.iter().find_map(|event| match event {
+        GameEvent::BlindPosted { seat, kind: ForcedBet::SmallBlind, .. } => Some(*seat),
+        _ => None,
+    });
+    assert_eq!(small_blind, Some(button));
+
+    let first_actions: Vec<(Street, usize)> = events.iter()
+        .scan(Street::PreFlop, |street, event| {
+            if let GameEvent::StreetAdvanced { street: next } = event {
+                *street = *next;
+            }
+            Some((*street, event.clone()))
+        })
+        .filter_map(|(street, event)| match event {
+            GameEvent::PlayerActed { seat, .. } => Some((street, seat)),
+            _ => None,
+        })
+        .collect();
+    // The button acts first before the flop and last after it
+    assert_eq!(first_actions[0], (Street::PreFlop, button));
+    let flop_first = first_actions.iter().find(|(street, _)| *street == Street::Flop).unwrap();
+    assert_ne!(flop_first.1, button);
+}
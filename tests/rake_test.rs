@@ -0,0 +1,117 @@
+use rust_deck::chips::Chips;
+use rust_deck::deck::Rank;
+use rust_deck::event::GameEvent;
+use rust_deck::game::{Game, GameResult};
+use rust_deck::player::PlayerAction;
+use rust_deck::rake::Rake;
+use rust_deck::strategy::{CallingStation, Strategy, TableView};
+
+mod common;
+
+// Checks when it can and folds otherwise
+struct Folder;
+
+impl Strategy for Folder {
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips) {
+        if view.legal_actions.contains(&PlayerAction::Check) {
+            return (PlayerAction::Check, Chips::ZERO);
+        }
+        (PlayerAction::Fold, Chips::ZERO)
+    }
+}
+
+// Calls before the flop and then shoves
+struct FlopShover;
+
+impl Strategy for FlopShover {
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips) {
+        if view.community.cards[0].rank == Rank::None {
+            return CallingStation.decide(view);
+        }
+        (PlayerAction::AllIn, Chips::ZERO)
+    }
+}
+
+// Calls before the flop and then gives up
+struct FlopFolder;
+
+impl Strategy for FlopFolder {
+    fn decide(&mut self, view: &TableView) -> (PlayerAction, Chips) {
+        if view.community.cards[0].rank == Rank::None {
+            return CallingStation.decide(view);
+        }
+        Folder.decide(view)
+    }
+}
+
+const RAKE: Rake = Rake { percent: 10, cap: Some(Chips::new(20)), no_flop_no_drop: true };
+
+fn table(strategy: fn() -> Box<dyn Strategy>) -> Game {
+    let players = (0..3).map(|i| common::player(i, 1000, strategy())).collect();
+    let mut game = Game::with_seed(players, 41);
+    game.set_rake(RAKE);
+    game
+}
+
+#[test]
+fn test_rake_take() {
+    assert_eq!(RAKE.take(Chips::new(150), true), Chips::new(15));
+    assert_eq!(RAKE.take(Chips::new(1000), true), Chips::new(20));
+    assert_eq!(RAKE.take(Chips::new(1000), false), Chips::ZERO);
+    let uncapped = Rake { cap: None, no_flop_no_drop: false, ..RAKE };
+    assert_eq!(uncapped.take(Chips::new(1000), false), Chips::new(100));
+    assert_eq!(Rake::default().take(Chips::new(1000), true), Chips::ZERO);
+}
+
+#[test]
+fn test_rake_is_kept_apart_from_the_banks() {
+    let mut game = table(|| Box::new(CallingStation));
+    let events = game.event_channel();
+    let mut raked = Chips::ZERO;
+    for _ in 0..10 {
+        if let GameResult::HandFinished(result) = game.play_hand() {
+            // Three callers put 150 in, so 15 comes out of every pot
+            assert_eq!(result.rake, Chips::new(15));
+            let paid: Chips = result.payouts.iter().map(|payout| payout.amount).sum();
+            assert_eq!(paid, Chips::new(135));
+            raked += result.rake;
+        }
+    }
+    assert_eq!(game.rake_collected(), raked);
+    let banks: Chips = game.players().iter().map(|player| player.bank).sum();
+    assert_eq!(banks + game.rake_collected(), Chips::new(3000));
+    let taken = events.try_iter().filter(|event| matches!(event, GameEvent::RakeTaken { .. })).count();
+    assert_eq!(taken, 10);
+}
+
+#[test]
+fn test_no_flop_no_drop() {
+    let mut game = table(|| Box::new(Folder));
+    for _ in 0..5 {
+        if let GameResult::HandFinished(result) = game.play_hand() {
+            assert_eq!(result.rake, Chips::ZERO);
+        }
+    }
+    assert_eq!(game.rake_collected(), Chips::ZERO);
+}
+
+#[test]
+fn test_only_the_uncalled_bet_is_not_raked() {
+    let players = vec![
+        common::player(0, 10_000, Box::new(FlopShover)),
+        common::player(1, 1000, Box::new(FlopFolder)),
+        common::player(2, 1000, Box::new(FlopFolder)),
+    ];
+    let mut game = Game::with_seed(players, 41);
+    game.set_rake(Rake { percent: 10, cap: None, no_flop_no_drop: false });
+    let GameResult::HandFinished(result) = game.play_hand() else {
+        panic!("the hand should finish");
+    };
+
+    // Everyone folds to the shove, so the shover gets it back unraked. The 150
+    // called before the flop is still raked.
+    assert_eq!(result.rake, Chips::new(15));
+    let shover = game.players().iter().find(|player| player.id() == 0).unwrap();
+    assert_eq!(shover.bank, Chips::new(10_085));
+    assert_eq!(game.rake_collected(), Chips::new(15));
+}
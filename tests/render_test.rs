@@ -0,0 +1,59 @@
+use rust_deck::chips::Chips;
+use rust_deck::deck::{Card, Community, Rank, Suit};
+use rust_deck::hand::Hand;
+use rust_deck::player::PlayerAction;
+use rust_deck::render::{self, Colors};
+use rust_deck::strategy::{LegalActions, Prompt, Strategy, TableView};
+
+#[test]
+fn test_render_cards() {
+    let ace: Card = "As".parse().unwrap();
+    let heart: Card = "Kh".parse().unwrap();
+    assert_eq!(render::card(&ace, Colors::None), "A♠");
+    assert_eq!(render::card(&heart, Colors::None), "K♥");
+
+    // Undealt slots show as placeholders
+    let flop: Community = "Qc Jd Th".parse().unwrap();
+    assert_eq!(render::cards(&flop.cards, Colors::None), "Q♣ J♦ T♥ -- --");
+    assert_eq!(render::hidden(2), "▒▒ ▒▒");
+
+    assert_eq!(render::card(&heart, Colors::TwoColor), "\x1b[31mK♥\x1b[0m");
+    assert_eq!(render::card(&ace, Colors::TwoColor), "A♠");
+    let diamond: Card = "Jd".parse().unwrap();
+    let club: Card = "Qc".parse().unwrap();
+    assert_eq!(render::card(&diamond, Colors::TwoColor), "\x1b[31mJ♦\x1b[0m");
+    assert_eq!(render::card(&diamond, Colors::FourColor), "\x1b[34mJ♦\x1b[0m");
+    assert_eq!(render::card(&club, Colors::FourColor), "\x1b[32mQ♣\x1b[0m");
+    assert_eq!(render::card(&Card { suit: Suit::None, rank: Rank::None }, Colors::FourColor), "--");
+
+    assert_eq!("four".parse::<Colors>(), Ok(Colors::FourColor));
+    assert!("rainbow".parse::<Colors>().is_err());
+}
+
+#[test]
+fn test_prompt_renders_cards() {
+    let view = TableView {
+        seat: 0,
+        name: String::from("Player 0"),
+        hand: "AsKh".parse::<Hand>().unwrap(),
+        community: "Qc Jd Th".parse().unwrap(),
+        pot: Chips::new(20),
+        current_bid: Chips::ZERO,
+        bid: Chips::ZERO,
+        bank: Chips::new(100),
+        legal_actions: LegalActions {
+            actions: vec![PlayerAction::Fold, PlayerAction::Check],
+            ..LegalActions::default()
+        },
+    };
+    let mut output = Vec::new();
+    Prompt::new(&b"check\n"[..], &mut output).decide(&view);
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Community: Q♣ J♦ T♥ -- --"));
+    assert!(output.contains("Player 0's hand: A♠ K♥"));
+
+    let mut output = Vec::new();
+    Prompt::new(&b"check\n"[..], &mut output).with_colors(Colors::TwoColor).decide(&view);
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Player 0's hand: A♠ \x1b[31mK♥\x1b[0m"));
+}
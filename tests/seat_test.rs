@@ -0,0 +1,41 @@
+
+mod common;
+
+use common::{bot, table};
+
+#[test]
+fn test_new_player_posts_the_next_big_blind() {
+    let mut game = table(&[1000; 4], 29);
+    game.play_hand();
+    let seat = game.seat_player(bot(9, 1000)).unwrap();
+    assert_eq!(game.players()[seat].id(), 9);
+    assert_eq!(game.next_positions().big_blind, seat);
+    game.play_hand();
+    assert_eq!(game.positions().big_blind, seat);
+}
+
+#[test]
+fn test_new_player_at_a_heads_up_table_posts_the_big_blind() {
+    let mut game = table(&[1000; 2], 29);
+    game.play_hand();
+    let seat = game.seat_player(bot(9, 1000)).unwrap();
+    let next = game.next_positions();
+    assert_eq!(next.big_blind, seat);
+    assert_ne!(next.small_blind, seat);
+    game.play_hand();
+    assert_eq!(game.positions().big_blind, seat);
+}
+
+#[test]
+fn test_button_moves_on_after_a_player_leaves() {
+    let mut game = table(&[1000; 4], 31);
+    game.play_hand();
+    let button = game.positions().button;
+    let next_id = game.players()[(button + 1) % 4].id();
+
+    let left = game.remove_player(button).unwrap();
+    assert_eq!(game.players().len(), 3);
+    assert!(game.players().iter().all(|player| player.id() != left.id()));
+    game.play_hand();
+    assert_eq!(game.players()[game.positions().button].id(), next_id);
+}
@@ -0,0 +1,38 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_deck::deck::{Card, Deck};
+use rust_deck::event::GameEvent;
+use rust_deck::game::Game;
+use std::sync::mpsc::Receiver;
+
+mod common;
+
+use common::bots;
+
+fn deal_all(seed: u64) -> Vec<Card> {
+    let mut deck = Deck::new();
+    deck.shuffle_with(&mut StdRng::seed_from_u64(seed));
+    (0..52).map(|_| deck.deal()).collect()
+}
+
+fn play(seed: u64, hands: usize) -> Vec<GameEvent> {
+    let mut game = Game::with_seed(bots(&[1000; 4]), seed);
+    assert_eq!(game.seed(), seed);
+    let events: Receiver<GameEvent> = game.event_channel();
+    for _ in 0..hands {
+        game.play_hand();
+    }
+    events.try_iter().collect()
+}
+
+#[test]
+fn test_seeded_deck() {
+    assert_eq!(deal_all(42), deal_all(42));
+    assert_ne!(deal_all(42), deal_all(43));
+}
+
+#[test]
+fn test_seeded_game_replays() {
+    assert_eq!(play(7, 5), play(7, 5));
+    assert_ne!(play(7, 5), play(8, 5));
+}
@@ -0,0 +1,43 @@
+use rust_deck::chips::Chips;
+use rust_deck::game::{split_pot, winning_hands, GameResult};
+use rust_deck::hand::{HandRank, ScoringHands};
+use rust_deck::deck::Rank;
+
+mod common;
+
+#[test]
+fn test_winning_hands_ties() {
+    let ranks = [
+        HandRank::new(ScoringHands::OnePair, &[Rank::Ace, Rank::King, Rank::Queen, Rank::Jack]),
+        HandRank::new(ScoringHands::Straight, &[Rank::Nine]),
+        HandRank::new(ScoringHands::HighCard, &[Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Nine]),
+        HandRank::new(ScoringHands::Straight, &[Rank::Nine]),
+    ];
+    assert_eq!(winning_hands(&ranks), vec![1, 3]);
+    assert_eq!(winning_hands(&ranks[..1]), vec![0]);
+    assert!(winning_hands(&[]).is_empty());
+}
+
+#[test]
+fn test_split_pot() {
+    assert_eq!(split_pot(Chips::new(1000), 2), vec![Chips::new(500), Chips::new(500)]);
+    // The odd chip goes to the first seat left of the button
+    assert_eq!(split_pot(Chips::new(100), 3), vec![Chips::new(34), Chips::new(33), Chips::new(33)]);
+    assert_eq!(split_pot(Chips::new(75), 1), vec![Chips::new(75)]);
+}
+
+#[test]
+fn test_odd_chip_goes_left_of_the_button() {
+    // Three calling stations put in 15 each and two of them chop the 45
+    let mut game = common::table(&[1000; 3], 177);
+    game.set_blinds(Chips::new(5), Chips::new(15));
+    let GameResult::HandFinished(result) = game.play_hand() else {
+        panic!("the hand is played out");
+    };
+    assert_eq!(game.positions().button, 0);
+    let payouts: Vec<(usize, Chips)> = result.payouts.iter().map(|payout| (payout.seat, payout.amount)).collect();
+    // Seat 2 is the first winner left of the button, ahead of seat 0 on the button
+    assert_eq!(payouts, vec![(2, Chips::new(23)), (0, Chips::new(22))]);
+    assert_eq!(game.players()[2].bank, Chips::new(1008));
+    assert_eq!(game.players()[0].bank, Chips::new(1007));
+}
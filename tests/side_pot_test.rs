@@ -0,0 +1,59 @@
+use rust_deck::chips::Chips;
+use rust_deck::game::{build_pots, Game, GameResult, Pot};
+use rust_deck::player::PlayerAction;
+use rust_deck::strategy::{Strategy, TableView};
+
+mod common;
+
+// Goes all in every time it acts
+struct Shove;
+
+impl Strategy for Shove {
+    fn decide(&mut self, _view: &TableView) -> (PlayerAction, Chips) {
+        (PlayerAction::AllIn, Chips::ZERO)
+    }
+}
+
+#[test]
+fn test_build_pots_single_pot() {
+    let pots = build_pots(&[(Chips::new(10), true), (Chips::new(10), true), (Chips::new(2), false)]);
+    assert_eq!(pots, vec![Pot { amount: Chips::new(22), eligible: vec![0, 1] }]);
+}
+
+#[test]
+fn test_build_pots_all_in_side_pots() {
+    // Player 0 is all in short, player 1 all in for more, players 2 and 3 cover
+    // both and player 3 folds on the river after putting in 40
+    let pots = build_pots(&[(Chips::new(10), true), (Chips::new(25), true), (Chips::new(60), true), (Chips::new(40), false)]);
+    assert_eq!(pots, vec![
+        Pot { amount: Chips::new(40), eligible: vec![0, 1, 2] },
+        Pot { amount: Chips::new(45), eligible: vec![1, 2] },
+        Pot { amount: Chips::new(50), eligible: vec![2] },
+    ]);
+
+    // Every chip put in ends up in exactly one pot
+    let total: Chips = pots.iter().map(|pot| pot.amount).sum();
+    assert_eq!(total, Chips::new(135));
+}
+
+#[test]
+fn test_side_pots_in_a_played_hand() {
+    let players = [100, 300, 600, 1000].iter().enumerate()
+        .map(|(i, &bank)| common::player(i as u8, bank, Box::new(Shove)))
+        .collect();
+    let mut game = Game::with_seed(players, 17);
+    assert!(matches!(game.play_hand(), GameResult::HandFinished(_)));
+
+    // On 4h Td Kd 5s Jd, Bot 0 (9s Js) and Bot 1 (Jc 9h) chop the 400 main pot
+    // with jacks. Bot 1 also takes the 600 side pot Bot 0 is not in, Bot 2 (5c 7d)
+    // beats Bot 3 (6h As) for the last 600, and the 400 nobody called goes back
+    // to Bot 3.
+    let mut banks: Vec<(u8, Chips)> = game.players().iter().map(|player| (player.id(), player.bank)).collect();
+    banks.sort();
+    assert_eq!(banks, vec![
+        (0, Chips::new(200)),
+        (1, Chips::new(800)),
+        (2, Chips::new(600)),
+        (3, Chips::new(400)),
+    ]);
+}
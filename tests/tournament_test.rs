@@ -0,0 +1,113 @@
+use rust_deck::betting::Ante;
+use rust_deck::chips::Chips;
+use rust_deck::event::{ForcedBet, GameEvent};
+use rust_deck::game::Game;
+use rust_deck::tournament::{BlindLevel, BlindSchedule, LevelDuration, PayoutTable, Tournament, TournamentStatus};
+use std::time::Duration;
+
+mod common;
+
+use common::bots;
+
+fn level(small_blind: u64, ante: u64) -> BlindLevel {
+    BlindLevel {
+        small_blind: Chips::new(small_blind),
+        big_blind: Chips::new(small_blind * 2),
+        ante: if ante == 0 { Ante::None } else { Ante::PerPlayer(Chips::new(ante)) },
+    }
+}
+
+fn tournament(duration: LevelDuration) -> Tournament {
+    let players = bots(&[1000; 4]);
+    let schedule = BlindSchedule {
+        levels: vec![level(10, 0), level(25, 0), level(50, 10), level(100, 25)],
+        duration,
+    };
+    Tournament::new(Game::with_seed(players, 19), schedule, Chips::new(500), PayoutTable::new(&[50, 30, 20]).unwrap())
+}
+
+#[test]
+fn test_payout_table() {
+    assert!(PayoutTable::new(&[50, 30]).is_none());
+    let prizes = PayoutTable::new(&[50, 30, 20]).unwrap().prizes(Chips::new(1001));
+    assert_eq!(prizes, vec![Chips::new(501), Chips::new(300), Chips::new(200)]);
+}
+
+#[test]
+fn test_levels_by_hands_and_time() {
+    let schedule = BlindSchedule { levels: vec![level(10, 0), level(25, 0)], duration: LevelDuration::Hands(10) };
+    assert_eq!(schedule.level_at(9, Duration::ZERO), 0);
+    assert_eq!(schedule.level_at(10, Duration::ZERO), 1);
+    assert_eq!(schedule.level_at(500, Duration::ZERO), 1);
+
+    let mut tournament = tournament(LevelDuration::Time(Duration::from_secs(600)));
+    let events = tournament.table_mut(0).event_channel();
+    tournament.play_hand_at(Duration::from_secs(1200));
+    assert_eq!(tournament.level(), 2);
+    let antes = events.try_iter()
+        .filter(|event| matches!(event, GameEvent::BlindPosted { kind: ForcedBet::Ante, amount, .. } if *amount == Chips::new(10)))
+        .count();
+    assert_eq!(antes, 4);
+}
+
+#[test]
+fn test_tournament_pays_finishing_places() {
+    let mut tournament = tournament(LevelDuration::Hands(5));
+    let mut knocked_out = Vec::new();
+    let standings = loop {
+        match tournament.play_hand() {
+            TournamentStatus::Running { eliminated } => knocked_out.extend(eliminated.into_iter().rev()),
+            TournamentStatus::Finished(standings) => break standings,
+        }
+    };
+    assert!(tournament.hands_played() > 0);
+
+    let places: Vec<usize> = standings.iter().map(|standing| standing.place).collect();
+    assert_eq!(places, vec![1, 2, 3, 4]);
+    // The first player out finishes last
+    let out: Vec<u8> = standings.iter().skip(1).rev().map(|standing| standing.id).collect();
+    assert_eq!(tournament.eliminated(), out);
+    // The last player out is only reported with the final standings
+    assert!(out.starts_with(&knocked_out));
+
+    let prizes: Vec<Chips> = standings.iter().map(|standing| standing.prize).collect();
+    assert_eq!(prizes, vec![Chips::new(1000), Chips::new(600), Chips::new(400), Chips::ZERO]);
+    let winner = &tournament.tables()[0].players()[0];
+    assert_eq!((winner.id(), winner.bank), (standings[0].id, Chips::new(4000)));
+}
+
+#[test]
+fn test_multi_table_balancing() {
+    let players = bots(&[1000; 20]);
+    let schedule = BlindSchedule {
+        levels: vec![level(10, 0), level(25, 0), level(50, 10), level(100, 25)],
+        duration: LevelDuration::Hands(5),
+    };
+    let mut tournament = Tournament::multi_table(players, 6, 23, schedule, Chips::new(100), PayoutTable::new(&[65, 35]).unwrap());
+    let sizes: Vec<usize> = tournament.tables().iter().map(|table| table.players().len()).collect();
+    assert_eq!(sizes, vec![5, 5, 5, 5]);
+
+    let mut saw_tables = Vec::new();
+    let standings = loop {
+        match tournament.play_hand() {
+            TournamentStatus::Running { .. } => {
+                let sizes: Vec<usize> = tournament.tables().iter().map(|table| table.players().len()).collect();
+                let remaining: usize = sizes.iter().sum();
+                // As few tables as fit everyone, and never more than one seat apart
+                assert_eq!(sizes.len(), remaining.div_ceil(6));
+                assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1);
+                saw_tables.push(sizes.len());
+            },
+            TournamentStatus::Finished(standings) => break standings,
+        }
+    };
+    // Down to the final table before the end
+    assert!(saw_tables.contains(&1));
+    assert_eq!(standings.len(), 20);
+    let mut ids: Vec<u8> = standings.iter().map(|standing| standing.id).collect();
+    ids.sort();
+    assert_eq!(ids, (0..20).collect::<Vec<u8>>());
+    assert_eq!(standings[0].prize + standings[1].prize, Chips::new(2000));
+    let chips: Chips = tournament.tables().iter().flat_map(|table| table.players().iter()).map(|player| player.bank).sum();
+    assert_eq!(chips, Chips::new(20_000));
+}